@@ -0,0 +1,320 @@
+use std::fmt;
+
+use bech32::{Bech32, Hrp, primitives::decode::CheckedHrpstring};
+
+use crate::{
+    chain::{Chain, MinitiaType},
+    common::eip55_checksum,
+    registry::Registry,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressError {
+    InvalidBech32 { address: String },
+    InvalidPrefix { prefix: String },
+    WrongPrefix { address: String, expected: String },
+    InvalidHex { address: String },
+    BadChecksum { address: String },
+    InvalidLength { address: String, len: usize },
+    NotEvm { chain_name: String },
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::InvalidBech32 { address } => {
+                write!(f, "`{address}` is not a valid bech32 address")
+            }
+            AddressError::InvalidPrefix { prefix } => {
+                write!(f, "`{prefix}` is not a valid bech32 prefix")
+            }
+            AddressError::WrongPrefix { address, expected } => {
+                write!(f, "`{address}` does not have the `{expected}` prefix")
+            }
+            AddressError::InvalidHex { address } => {
+                write!(f, "`{address}` is not a 0x hex address")
+            }
+            AddressError::BadChecksum { address } => {
+                write!(f, "`{address}` fails its EIP-55 checksum")
+            }
+            AddressError::InvalidLength { address, len } => {
+                write!(f, "`{address}` decodes to {len} bytes")
+            }
+            AddressError::NotEvm { chain_name } => {
+                write!(f, "chain `{chain_name}` has no EVM address form")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+/// Decodes a bech32 address into its prefix and account bytes, which are 20 bytes for keys
+/// and 32 bytes for module or contract accounts.
+pub fn decode_bech32(address: &str) -> Result<(String, Vec<u8>), AddressError> {
+    let invalid = || AddressError::InvalidBech32 {
+        address: address.to_string(),
+    };
+    let checked = CheckedHrpstring::new::<Bech32>(address).map_err(|_| invalid())?;
+    let bytes: Vec<u8> = checked.byte_iter().collect();
+    if !matches!(bytes.len(), 20 | 32) {
+        return Err(AddressError::InvalidLength {
+            address: address.to_string(),
+            len: bytes.len(),
+        });
+    }
+    Ok((checked.hrp().to_lowercase(), bytes))
+}
+
+pub fn encode_bech32(prefix: &str, bytes: &[u8]) -> Result<String, AddressError> {
+    let hrp = Hrp::parse(prefix).map_err(|_| AddressError::InvalidPrefix {
+        prefix: prefix.to_string(),
+    })?;
+    bech32::encode::<Bech32>(hrp, bytes).map_err(|_| AddressError::InvalidPrefix {
+        prefix: prefix.to_string(),
+    })
+}
+
+/// Re-encodes a bech32 address under another prefix.
+pub fn convert_prefix(address: &str, prefix: &str) -> Result<String, AddressError> {
+    let (_, bytes) = decode_bech32(address)?;
+    encode_bech32(prefix, &bytes)
+}
+
+/// Decodes a `0x` address. Mixed-case input must carry a valid EIP-55 checksum.
+pub fn decode_hex(address: &str) -> Result<[u8; 20], AddressError> {
+    let invalid = || AddressError::InvalidHex {
+        address: address.to_string(),
+    };
+    let digits = address.strip_prefix("0x").ok_or_else(invalid)?;
+    let mut bytes = [0u8; 20];
+    hex::decode_to_slice(digits, &mut bytes).map_err(|_| invalid())?;
+    let mixed_case = digits.bytes().any(|b| b.is_ascii_lowercase())
+        && digits.bytes().any(|b| b.is_ascii_uppercase());
+    if mixed_case && eip55_checksum(&bytes) != digits {
+        return Err(AddressError::BadChecksum {
+            address: address.to_string(),
+        });
+    }
+    Ok(bytes)
+}
+
+pub fn encode_hex(bytes: &[u8; 20]) -> String {
+    format!("0x{}", eip55_checksum(bytes))
+}
+
+impl Chain {
+    /// Whether accounts on this chain also have a `0x` form, i.e. the chain declares an
+    /// `evm_chain_id` or runs MiniEVM.
+    pub fn is_evm(&self) -> bool {
+        self.evm_chain_id.is_some()
+            || self
+                .metadata
+                .minitia
+                .as_ref()
+                .is_some_and(|m| m.ty == MinitiaType::MiniEVM)
+    }
+
+    /// Decodes an address of this chain: bech32 with the chain's prefix, or `0x` hex on EVM
+    /// chains.
+    pub fn parse_address(&self, address: &str) -> Result<Vec<u8>, AddressError> {
+        if address.starts_with("0x") {
+            if !self.is_evm() {
+                return Err(AddressError::NotEvm {
+                    chain_name: self.chain_name.clone(),
+                });
+            }
+            return decode_hex(address).map(Vec::from);
+        }
+        let (prefix, bytes) = decode_bech32(address)?;
+        if prefix != self.bech32_prefix {
+            return Err(AddressError::WrongPrefix {
+                address: address.to_string(),
+                expected: self.bech32_prefix.clone(),
+            });
+        }
+        Ok(bytes)
+    }
+
+    pub fn is_valid_address(&self, address: &str) -> bool {
+        self.parse_address(address).is_ok()
+    }
+
+    /// Re-encodes an address from any chain, bech32 or `0x`, under this chain's prefix.
+    pub fn to_bech32(&self, address: &str) -> Result<String, AddressError> {
+        let bytes = match address.strip_prefix("0x") {
+            Some(_) => decode_hex(address)?.to_vec(),
+            None => decode_bech32(address)?.1,
+        };
+        encode_bech32(&self.bech32_prefix, &bytes)
+    }
+
+    /// The checksummed `0x` form of an address on an EVM chain.
+    pub fn to_hex(&self, address: &str) -> Result<String, AddressError> {
+        if !self.is_evm() {
+            return Err(AddressError::NotEvm {
+                chain_name: self.chain_name.clone(),
+            });
+        }
+        if address.starts_with("0x") {
+            return decode_hex(address).map(|b| encode_hex(&b));
+        }
+        let (_, bytes) = decode_bech32(address)?;
+        let bytes: [u8; 20] =
+            bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| AddressError::InvalidLength {
+                    address: address.to_string(),
+                    len: bytes.len(),
+                })?;
+        Ok(encode_hex(&bytes))
+    }
+}
+
+impl Registry {
+    /// The same account, given as an address on `chain`, on every registered chain that
+    /// derives keys the same way: the same `slip44`, and so the same key algorithm. Chains on
+    /// another coin type are left out, as the same bytes there would be an account the holder
+    /// has no key for.
+    pub fn addresses_for<'a>(
+        &'a self,
+        chain: &Chain,
+        address: &str,
+    ) -> Result<Vec<(&'a Chain, String)>, AddressError> {
+        let bytes = chain.parse_address(address)?;
+        self.chains()
+            .iter()
+            .filter(|c| c.slip44 == chain.slip44)
+            .map(|c| Ok((c, encode_bech32(&c.bech32_prefix, &bytes)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain::ChainList, profile::ProfileList};
+
+    const HEX: &str = "0xE1Ff7038eAAAF027031688E1535a055B2Bac2546";
+    const INIT: &str = "init1u8lhqw824tczwqck3rs4xks9tv46cf2xuegt3z";
+    const COSMOS: &str = "cosmos1u8lhqw824tczwqck3rs4xks9tv46cf2xj0gc6q";
+
+    fn chain(chain_name: &str, prefix: &str, slip44: u32, extra: &str) -> String {
+        format!(
+            r#"{{
+                "chain_id": "{chain_name}-1",
+                "chain_name": "{chain_name}",
+                "pretty_name": "{chain_name}",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{{extra}}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}},
+                "slip44": {slip44},
+                "bech32_prefix": "{prefix}",
+                "network_type": "mainnet"
+            }}"#
+        )
+    }
+
+    fn registry() -> Registry {
+        let chains = [
+            chain("initia", "init", 60, r#""is_l1": true"#),
+            chain(
+                "yominet",
+                "init",
+                60,
+                r#""minitia": {"type": "minievm", "version": "v1.0.0"}"#,
+            ),
+            chain("cosmoshub", "cosmos", 118, ""),
+            chain("evmos", "evmos", 60, ""),
+        ];
+        let chains: ChainList = serde_json::from_str(&format!("[{}]", chains.join(",")))
+            .expect("Failed to deserialize chains");
+        Registry::new(chains, vec![], ProfileList(vec![]))
+    }
+
+    #[test]
+    fn test_bech32_helpers() {
+        assert_eq!(convert_prefix(INIT, "cosmos").unwrap(), COSMOS);
+        assert_eq!(decode_bech32(INIT).unwrap().0, "init");
+        assert!(matches!(
+            decode_bech32("init1u8lhqw824tczwqck3rs4xks9tv46cf2xuegt3q"),
+            Err(AddressError::InvalidBech32 { .. })
+        ));
+        assert!(matches!(
+            convert_prefix(INIT, "Bad Prefix"),
+            Err(AddressError::InvalidPrefix { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hex_helpers() {
+        let bytes = decode_hex(HEX).unwrap();
+        assert_eq!(encode_hex(&bytes), HEX);
+        assert_eq!(decode_hex(&HEX.to_lowercase()).unwrap(), bytes);
+        assert!(matches!(
+            decode_hex("0xe1Ff7038eAAAF027031688E1535a055B2Bac2546"),
+            Err(AddressError::BadChecksum { .. })
+        ));
+        assert!(matches!(
+            decode_hex("0x1234"),
+            Err(AddressError::InvalidHex { .. })
+        ));
+    }
+
+    #[test]
+    fn test_chain_addresses() {
+        let registry = registry();
+        let initia = registry.chain_by_name("initia").unwrap();
+        let yominet = registry.chain_by_name("yominet").unwrap();
+        let cosmoshub = registry.chain_by_name("cosmoshub").unwrap();
+
+        assert!(!initia.is_evm());
+        assert!(yominet.is_evm());
+        assert!(initia.is_valid_address(INIT));
+        assert!(!initia.is_valid_address(HEX));
+        assert!(yominet.is_valid_address(HEX));
+        assert_eq!(
+            cosmoshub.parse_address(INIT),
+            Err(AddressError::WrongPrefix {
+                address: INIT.to_string(),
+                expected: "cosmos".to_string()
+            })
+        );
+
+        assert_eq!(yominet.to_hex(INIT).unwrap(), HEX);
+        assert_eq!(yominet.to_bech32(HEX).unwrap(), INIT);
+        assert_eq!(cosmoshub.to_bech32(INIT).unwrap(), COSMOS);
+        assert!(matches!(
+            initia.to_hex(INIT),
+            Err(AddressError::NotEvm { .. })
+        ));
+
+        let addresses_for = |chain, address| -> Vec<_> {
+            registry
+                .addresses_for(chain, address)
+                .unwrap()
+                .into_iter()
+                .map(|(c, a)| (c.chain_name.as_str(), a))
+                .collect()
+        };
+        assert_eq!(
+            addresses_for(yominet, HEX),
+            vec![
+                ("initia", INIT.to_string()),
+                ("yominet", INIT.to_string()),
+                ("evmos", convert_prefix(INIT, "evmos").unwrap()),
+            ]
+        );
+        assert_eq!(
+            addresses_for(cosmoshub, COSMOS),
+            vec![("cosmoshub", COSMOS.to_string())]
+        );
+        assert!(matches!(
+            registry.addresses_for(initia, HEX),
+            Err(AddressError::NotEvm { .. })
+        ));
+    }
+}
@@ -0,0 +1,455 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::asset::{Asset, DenomUnit};
+
+/// An exact non-negative decimal stored as `atomics / 10^scale`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Decimal {
+    atomics: u128,
+    scale: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AmountError {
+    Invalid {
+        input: String,
+    },
+    UnknownUnit {
+        denom: String,
+    },
+    /// The amount has more fractional digits than the target unit can represent.
+    Precision {
+        amount: Decimal,
+        exponent: u32,
+    },
+    Overflow,
+    /// A denom matched units of more than one asset.
+    Ambiguous {
+        denom: String,
+    },
+}
+
+impl fmt::Display for AmountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AmountError::Invalid { input } => write!(f, "`{input}` is not a valid amount"),
+            AmountError::UnknownUnit { denom } => write!(f, "unknown denom unit `{denom}`"),
+            AmountError::Precision { amount, exponent } => write!(
+                f,
+                "{amount} has more than {exponent} fractional digits of precision"
+            ),
+            AmountError::Overflow => f.write_str("amount overflows u128"),
+            AmountError::Ambiguous { denom } => {
+                write!(f, "denom `{denom}` matches more than one asset")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AmountError {}
+
+impl Decimal {
+    pub const MAX_SCALE: u32 = 38;
+
+    pub fn new(atomics: u128, scale: u32) -> Result<Self, AmountError> {
+        if scale > Self::MAX_SCALE {
+            return Err(AmountError::Overflow);
+        }
+        Ok(Self { atomics, scale })
+    }
+
+    pub fn from_integer(value: u128) -> Self {
+        Self {
+            atomics: value,
+            scale: 0,
+        }
+    }
+
+    pub fn atomics(&self) -> u128 {
+        self.atomics
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.atomics == 0
+    }
+
+    /// Drops trailing fractional zeros, e.g. `1.500` becomes `1.5`.
+    pub fn normalize(self) -> Self {
+        let Self {
+            mut atomics,
+            mut scale,
+        } = self;
+        while scale > 0 && atomics % 10 == 0 {
+            atomics /= 10;
+            scale -= 1;
+        }
+        Self { atomics, scale }
+    }
+
+    /// Re-expresses the value with exactly `scale` fractional digits, failing instead of
+    /// discarding non-zero digits.
+    pub fn rescale(self, scale: u32) -> Result<Self, AmountError> {
+        match scale.cmp(&self.scale) {
+            Ordering::Equal => Ok(self),
+            Ordering::Greater => {
+                let atomics = pow10(scale - self.scale)
+                    .and_then(|factor| self.atomics.checked_mul(factor))
+                    .ok_or(AmountError::Overflow)?;
+                Decimal::new(atomics, scale)
+            }
+            Ordering::Less => {
+                let factor = pow10(self.scale - scale).ok_or(AmountError::Overflow)?;
+                if !self.atomics.is_multiple_of(factor) {
+                    return Err(AmountError::Precision {
+                        amount: self,
+                        exponent: scale,
+                    });
+                }
+                Ok(Self {
+                    atomics: self.atomics / factor,
+                    scale,
+                })
+            }
+        }
+    }
+
+    /// Rounds to at most `decimals` fractional digits.
+    pub fn round(self, decimals: u32, rounding: Rounding) -> Result<Self, AmountError> {
+        if decimals >= self.scale {
+            return Ok(self);
+        }
+        let factor = pow10(self.scale - decimals).ok_or(AmountError::Overflow)?;
+        let (quotient, remainder) = (self.atomics / factor, self.atomics % factor);
+        let round_up = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => remainder > 0,
+            Rounding::HalfUp => remainder >= factor.div_ceil(2),
+        };
+        let atomics = if round_up {
+            quotient.checked_add(1).ok_or(AmountError::Overflow)?
+        } else {
+            quotient
+        };
+        Decimal::new(atomics, decimals)
+    }
+
+    pub fn integer_part(&self) -> u128 {
+        self.atomics / pow10(self.scale).unwrap_or(u128::MAX)
+    }
+
+    /// The fractional digits, zero-padded to `scale` characters.
+    pub fn fractional_digits(&self) -> String {
+        if self.scale == 0 {
+            return String::new();
+        }
+        let frac = self.atomics % pow10(self.scale).unwrap_or(u128::MAX);
+        format!("{frac:0width$}", width = self.scale as usize)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    #[default]
+    HalfUp,
+    Down,
+    Up,
+}
+
+/// An amount of a base denom, as carried in Cosmos SDK messages.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Coin {
+    pub denom: String,
+    #[serde(with = "u128_string")]
+    pub amount: u128,
+}
+
+impl Coin {
+    pub fn new(amount: u128, denom: impl Into<String>) -> Self {
+        Self {
+            denom: denom.into(),
+            amount,
+        }
+    }
+}
+
+impl fmt::Display for Coin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+mod u128_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+pub(crate) fn pow10(exp: u32) -> Option<u128> {
+    10u128.checked_pow(exp)
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (self.normalize(), other.normalize());
+        a.atomics == b.atomics && a.scale == b.scale
+    }
+}
+
+impl Eq for Decimal {}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let normalized = self.normalize();
+        write!(f, "{}", normalized.integer_part())?;
+        if normalized.scale > 0 {
+            write!(f, ".{}", normalized.fractional_digits())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = AmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AmountError::Invalid {
+            input: s.to_string(),
+        };
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        if int.is_empty() || !int.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        if s.contains('.') && frac.is_empty() || !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let scale = frac.len() as u32;
+        let mut atomics: u128 = 0;
+        for b in int.bytes().chain(frac.bytes()) {
+            atomics = atomics
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((b - b'0') as u128))
+                .ok_or(AmountError::Overflow)?;
+        }
+        Decimal::new(atomics, scale)
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Asset {
+    pub fn denom_unit(&self, denom: &str) -> Option<&DenomUnit> {
+        self.denom_units.iter().find(|u| u.denom == denom)
+    }
+
+    /// The exponent of the display unit.
+    pub fn decimals(&self) -> Option<u32> {
+        self.denom_unit(&self.display).map(|u| u.exponent)
+    }
+
+    fn exponent_of(&self, denom: &str) -> Result<u32, AmountError> {
+        self.denom_unit(denom)
+            .map(|u| u.exponent)
+            .ok_or_else(|| AmountError::UnknownUnit {
+                denom: denom.to_string(),
+            })
+    }
+
+    /// Expresses a base-unit amount in the named unit, e.g. `1500000` uinit as `1.5` INIT.
+    pub fn to_unit(&self, base_amount: u128, unit: &str) -> Result<Decimal, AmountError> {
+        Decimal::new(base_amount, self.exponent_of(unit)?)
+    }
+
+    /// Converts an amount expressed in the named unit back to base units.
+    pub fn from_unit(&self, amount: Decimal, unit: &str) -> Result<u128, AmountError> {
+        let exponent = self.exponent_of(unit)?;
+        // `atomics / 10^scale` units are worth `atomics * 10^(exponent - scale)` base units.
+        if exponent >= amount.scale {
+            pow10(exponent - amount.scale)
+                .and_then(|factor| amount.atomics.checked_mul(factor))
+                .ok_or(AmountError::Overflow)
+        } else {
+            amount
+                .rescale(exponent)
+                .map(|d| d.atomics)
+                .map_err(|_| AmountError::Precision { amount, exponent })
+        }
+    }
+
+    pub fn convert(&self, amount: Decimal, from: &str, to: &str) -> Result<Decimal, AmountError> {
+        self.to_unit(self.from_unit(amount, from)?, to)
+    }
+
+    pub fn to_display(&self, base_amount: u128) -> Result<Decimal, AmountError> {
+        self.to_unit(base_amount, &self.display)
+    }
+
+    pub fn from_display(&self, amount: Decimal) -> Result<u128, AmountError> {
+        self.from_unit(amount, &self.display)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ImageType;
+
+    fn asset(base: &str, display: &str, exponent: u32) -> Asset {
+        Asset {
+            description: "Test token".to_string(),
+            denom_units: vec![
+                DenomUnit {
+                    denom: base.to_string(),
+                    exponent: 0,
+                },
+                DenomUnit {
+                    denom: format!("m{display}"),
+                    exponent: exponent / 2,
+                },
+                DenomUnit {
+                    denom: display.to_string(),
+                    exponent,
+                },
+            ],
+            base: base.to_string(),
+            display: display.to_string(),
+            name: display.to_string(),
+            symbol: display.to_string(),
+            coingecko_id: None,
+            type_asset: None,
+            images: vec![],
+            logo_uris: ImageType::PNG("https://test.com/logo.png".to_string()),
+            traces: vec![],
+        }
+    }
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().expect("Failed to parse decimal")
+    }
+
+    #[test]
+    fn test_decimal_parse_and_display() {
+        assert_eq!(dec("1.500").to_string(), "1.5");
+        assert_eq!(dec("0.000001").to_string(), "0.000001");
+        assert_eq!(dec("42").to_string(), "42");
+        assert_eq!(dec("0.0").to_string(), "0");
+        assert_eq!(dec("1.50"), dec("1.5"));
+        assert_ne!(dec("1.05"), dec("1.5"));
+
+        for input in ["", ".5", "1.", "-1", "1e6", "1,5", " 1"] {
+            assert!(
+                matches!(input.parse::<Decimal>(), Err(AmountError::Invalid { .. })),
+                "expected `{input}` to be rejected"
+            );
+        }
+        assert_eq!(
+            "1000000000000000000000000000000000000000".parse::<Decimal>(),
+            Err(AmountError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_rescale() {
+        assert_eq!(dec("1.5").rescale(6).unwrap().atomics(), 1_500_000);
+        assert_eq!(dec("1.500000").rescale(1).unwrap().atomics(), 15);
+        assert!(matches!(
+            dec("1.55").rescale(1),
+            Err(AmountError::Precision { .. })
+        ));
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(
+            dec("1.2345").round(2, Rounding::HalfUp).unwrap(),
+            dec("1.23")
+        );
+        assert_eq!(
+            dec("1.235").round(2, Rounding::HalfUp).unwrap(),
+            dec("1.24")
+        );
+        assert_eq!(dec("1.231").round(2, Rounding::Up).unwrap(), dec("1.24"));
+        assert_eq!(dec("1.239").round(2, Rounding::Down).unwrap(), dec("1.23"));
+        assert_eq!(dec("9.99").round(0, Rounding::HalfUp).unwrap(), dec("10"));
+        assert_eq!(dec("1.5").round(4, Rounding::Down).unwrap(), dec("1.5"));
+    }
+
+    #[test]
+    fn test_coin_serde() {
+        let coin = Coin::new(1_500_000, "uinit");
+        assert_eq!(coin.to_string(), "1500000uinit");
+        let json = serde_json::to_string(&coin).expect("Failed to serialize coin");
+        assert_eq!(json, r#"{"denom":"uinit","amount":"1500000"}"#);
+        assert_eq!(
+            serde_json::from_str::<Coin>(&json).expect("Failed to deserialize coin"),
+            coin
+        );
+    }
+
+    #[test]
+    fn test_to_and_from_unit() {
+        let init = asset("uinit", "INIT", 6);
+
+        assert_eq!(init.to_display(1_500_000).unwrap(), dec("1.5"));
+        assert_eq!(init.to_unit(1_500_000, "mINIT").unwrap(), dec("1500"));
+        assert_eq!(init.to_unit(1_500_000, "uinit").unwrap(), dec("1500000"));
+        assert_eq!(init.from_display(dec("1.5")).unwrap(), 1_500_000);
+        assert_eq!(init.from_unit(dec("0.001"), "mINIT").unwrap(), 1);
+        assert_eq!(
+            init.convert(dec("2.5"), "INIT", "mINIT").unwrap(),
+            dec("2500")
+        );
+
+        assert_eq!(
+            init.from_display(dec("0.0000001")),
+            Err(AmountError::Precision {
+                amount: dec("0.0000001"),
+                exponent: 6
+            })
+        );
+        assert_eq!(
+            init.to_unit(1, "GINIT"),
+            Err(AmountError::UnknownUnit {
+                denom: "GINIT".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_eighteen_decimals_beyond_u64() {
+        let eth = asset("wei", "ETH", 18);
+        let base = eth.from_display(dec("123456.000000000000000001")).unwrap();
+
+        assert_eq!(base, 123_456_000_000_000_000_000_001);
+        assert!(base > u64::MAX as u128);
+        assert_eq!(
+            eth.to_display(base).unwrap().to_string(),
+            "123456.000000000000000001"
+        );
+        assert_eq!(eth.from_display(dec("1")).unwrap(), 10u128.pow(18));
+    }
+}
@@ -45,6 +45,10 @@ structstruck::strike! {
             pub url: String,
             pub tx_page: String,
             pub account_page: String,
+            pub block_page: Option<String>,
+            pub validator_page: Option<String>,
+            pub proposal_page: Option<String>,
+            pub evm_tx_page: Option<String>,
         }>,
         pub metadata: pub struct Metadata {
             pub op_bridge_id: Option<String>,
@@ -428,6 +432,10 @@ mod tests {
                 url: "https://scan.test.com".to_string(),
                 tx_page: "https://scan.test.com/txs/${txHash}".to_string(),
                 account_page: "https://scan.test.com/accounts/${accountAddress}".to_string(),
+                block_page: Some("https://scan.test.com/blocks/${blockHeight}".to_string()),
+                validator_page: None,
+                proposal_page: None,
+                evm_tx_page: None,
             }],
             metadata: Metadata {
                 op_bridge_id: None,
@@ -0,0 +1,300 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    asset::AssetList,
+    chain::{Chain, ChainList},
+    profile::ProfileList,
+    registry::Registry,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://registry.initia.xyz";
+
+#[derive(Debug)]
+pub enum ClientError {
+    Http(reqwest::Error),
+    Status { url: String, status: u16 },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Http(err) => write!(f, "registry request failed: {err}"),
+            ClientError::Status { url, status } => {
+                write!(f, "registry request to {url} failed with status {status}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Http(err) => Some(err),
+            ClientError::Status { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(err: reqwest::Error) -> Self {
+        ClientError::Http(err)
+    }
+}
+
+/// Async client for the registry's published JSON layout.
+#[derive(Clone, Debug)]
+pub struct RegistryClient {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl Default for RegistryClient {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+impl RegistryClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_http_client(base_url, reqwest::Client::new())
+    }
+
+    pub fn with_http_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Self { base_url, http }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn chains_url(&self) -> String {
+        format!("{}/chains.json", self.base_url)
+    }
+
+    pub fn profiles_url(&self) -> String {
+        format!("{}/profiles.json", self.base_url)
+    }
+
+    pub fn assetlist_url(&self, chain_name: &str) -> String {
+        format!("{}/chains/{}/assetlist.json", self.base_url, chain_name)
+    }
+
+    pub async fn chains(&self) -> Result<ChainList, ClientError> {
+        self.get_json(&self.chains_url()).await
+    }
+
+    pub async fn profiles(&self) -> Result<ProfileList, ClientError> {
+        self.get_json(&self.profiles_url()).await
+    }
+
+    pub async fn assetlist(&self, chain_name: &str) -> Result<AssetList, ClientError> {
+        self.get_json(&self.assetlist_url(chain_name)).await
+    }
+
+    /// Fetches the assetlist a chain advertises in `Metadata::assetlist`, falling back to the
+    /// registry layout under the base URL when the chain does not declare one.
+    pub async fn assetlist_for_chain(&self, chain: &Chain) -> Result<AssetList, ClientError> {
+        match &chain.metadata.assetlist {
+            Some(url) => self.get_json(url).await,
+            None => self.assetlist(&chain.chain_name).await,
+        }
+    }
+
+    /// Fetches chains, profiles and the assetlist of every chain that declares one.
+    ///
+    /// Assetlists are always resolved under the base URL so the result is a consistent snapshot
+    /// of a single registry deployment.
+    pub async fn registry(&self) -> Result<Registry, ClientError> {
+        let chains = self.chains().await?;
+        let profiles = self.profiles().await?;
+
+        let mut assetlists = Vec::new();
+        for chain in chains.0.iter().filter(|c| c.metadata.assetlist.is_some()) {
+            assetlists.push(self.assetlist(&chain.chain_name).await?);
+        }
+
+        Ok(Registry::new(chains, assetlists, profiles))
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, ClientError> {
+        let response = self.http.get(url).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClientError::Status {
+                url: url.to_string(),
+                status: status.as_u16(),
+            });
+        }
+        Ok(response.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    const CHAINS_JSON: &str = r#"[
+        {
+            "chain_id": "interwoven-1",
+            "chain_name": "initia",
+            "pretty_name": "Initia",
+            "fees": {"fee_tokens": [{"denom": "uinit"}]},
+            "apis": {},
+            "explorers": [],
+            "metadata": {
+                "is_l1": true,
+                "assetlist": "https://registry.initia.xyz/chains/initia/assetlist.json"
+            },
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"},
+            "slip44": 60,
+            "bech32_prefix": "init",
+            "network_type": "mainnet"
+        },
+        {
+            "chain_id": "bare-1",
+            "chain_name": "bare",
+            "pretty_name": "Bare",
+            "fees": {"fee_tokens": []},
+            "apis": {},
+            "explorers": [],
+            "metadata": {},
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/bare.png"},
+            "slip44": 60,
+            "bech32_prefix": "init",
+            "network_type": "mainnet"
+        }
+    ]"#;
+
+    const PROFILES_JSON: &str = r##"[
+        {
+            "name": "initia",
+            "pretty_name": "Initia",
+            "category": "Infrastructure",
+            "description": "Initia L1",
+            "logo": "https://registry.initia.xyz/images/INIT.png",
+            "color": "#FFFFFF",
+            "status": "live",
+            "social": {"website": "https://initia.xyz", "twitter": "https://x.com/initia"}
+        }
+    ]"##;
+
+    const ASSETLIST_JSON: &str = r#"{
+        "$schema": "../../assetlist.schema.json",
+        "chain_name": "initia",
+        "assets": [
+            {
+                "description": "The native token of Initia",
+                "denom_units": [
+                    {"denom": "uinit", "exponent": 0},
+                    {"denom": "INIT", "exponent": 6}
+                ],
+                "base": "uinit",
+                "display": "INIT",
+                "name": "Initia Native Token",
+                "symbol": "INIT",
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+            }
+        ]
+    }"#;
+
+    /// Serves the fixtures above over plain HTTP and returns the base URL.
+    async fn spawn_registry_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind stand-in server");
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+                let (status, body) = match path {
+                    "/chains.json" => ("200 OK", CHAINS_JSON),
+                    "/profiles.json" => ("200 OK", PROFILES_JSON),
+                    "/chains/initia/assetlist.json" => ("200 OK", ASSETLIST_JSON),
+                    _ => ("404 Not Found", "not found"),
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        format!("http://{addr}/")
+    }
+
+    #[test]
+    fn test_urls() {
+        let client = RegistryClient::default();
+        assert_eq!(client.base_url(), "https://registry.initia.xyz");
+        assert_eq!(
+            client.chains_url(),
+            "https://registry.initia.xyz/chains.json"
+        );
+        assert_eq!(
+            client.profiles_url(),
+            "https://registry.initia.xyz/profiles.json"
+        );
+        assert_eq!(
+            client.assetlist_url("yominet"),
+            "https://registry.initia.xyz/chains/yominet/assetlist.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_local_server() {
+        let client = RegistryClient::new(spawn_registry_server().await);
+
+        let chains = client.chains().await.expect("Failed to fetch chains");
+        assert_eq!(chains.0.len(), 2);
+
+        let profiles = client.profiles().await.expect("Failed to fetch profiles");
+        assert_eq!(profiles.0[0].name, "initia");
+
+        let assetlist = client
+            .assetlist("initia")
+            .await
+            .expect("Failed to fetch assetlist");
+        assert_eq!(assetlist.assets[0].base, "uinit");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_registry_from_local_server() {
+        let client = RegistryClient::new(spawn_registry_server().await);
+
+        let registry = client.registry().await.expect("Failed to fetch registry");
+        assert_eq!(registry.chains().len(), 2);
+        assert_eq!(registry.assetlists().len(), 1);
+        assert!(registry.asset("initia", "uinit").is_some());
+        assert!(registry.profile("initia").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_status_error() {
+        let client = RegistryClient::new(spawn_registry_server().await);
+
+        match client.assetlist("unknown").await {
+            Err(ClientError::Status { url, status }) => {
+                assert_eq!(status, 404);
+                assert!(url.ends_with("/chains/unknown/assetlist.json"));
+            }
+            other => panic!("Expected status error, got {other:?}"),
+        }
+    }
+}
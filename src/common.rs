@@ -15,3 +15,23 @@ impl ImageType {
         }
     }
 }
+
+/// Formats an EVM address as EIP-55 mixed-case hex, without the `0x` prefix.
+pub fn eip55_checksum(address: &[u8; 20]) -> String {
+    use sha3::{Digest, Keccak256};
+
+    let lower = hex::encode(address);
+    let hash = Keccak256::digest(lower.as_bytes());
+    lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,1209 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    address::decode_hex,
+    asset::{
+        Asset, AssetList, DenomUnit, IbcCounterparty, IbcTraceChain, OpChain, OpCounterparty,
+        Trace, WrappedChain, WrappedCounterparty,
+    },
+    chain::{Apis, Chain, Endpoint, Explorer, FeeToken, Fees, Metadata},
+    common::{ImageType, eip55_checksum},
+    denom::{EVM_PREFIX, IBC_PREFIX},
+    hd::KeyAlgorithm,
+};
+
+/// The trace `provider` written for OPinit bridge traces.
+pub const OPINIT_PROVIDER: &str = "OPinit";
+
+/// The result of converting to or from the cosmos/chain-registry format, with the JSON paths of
+/// source fields the target format cannot represent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Converted<T> {
+    pub value: T,
+    /// JSON paths of source fields that were dropped, e.g. `$.metadata.op_bridge_id`.
+    pub lossy: Vec<String>,
+}
+
+impl<T> Converted<T> {
+    pub fn is_lossless(&self) -> bool {
+        self.lossy.is_empty()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CosmosError {
+    MissingSlip44 { chain_name: String },
+    MissingLogo { path: String },
+}
+
+impl fmt::Display for CosmosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CosmosError::MissingSlip44 { chain_name } => {
+                write!(f, "chain `{chain_name}` has no `slip44`")
+            }
+            CosmosError::MissingLogo { path } => {
+                write!(f, "`{path}` has neither `logo_URIs` nor `images`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CosmosError {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CosmosLogoUris {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub png: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub svg: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl From<&ImageType> for CosmosLogoUris {
+    fn from(image: &ImageType) -> Self {
+        match image {
+            ImageType::PNG(url) => Self {
+                png: Some(url.clone()),
+                svg: None,
+                extra: BTreeMap::new(),
+            },
+            ImageType::SVG(url) => Self {
+                png: None,
+                svg: Some(url.clone()),
+                extra: BTreeMap::new(),
+            },
+        }
+    }
+}
+
+impl CosmosLogoUris {
+    /// Every URI as an [`ImageType`], PNG first.
+    pub fn images(&self) -> Vec<ImageType> {
+        let png = self.png.clone().map(ImageType::PNG);
+        let svg = self.svg.clone().map(ImageType::SVG);
+        png.into_iter().chain(svg).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CosmosChain {
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub chain_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub network_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretty_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub chain_type: String,
+    pub chain_id: String,
+    pub bech32_prefix: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slip44: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_algos: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fees: Option<CosmosFees>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apis: Option<CosmosApis>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub explorers: Vec<CosmosExplorer>,
+    #[serde(rename = "logo_URIs", skip_serializing_if = "Option::is_none")]
+    pub logo_uris: Option<CosmosLogoUris>,
+    /// Chain-registry fields not modelled here, reported as lossy on import.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct CosmosFees {
+    pub fee_tokens: Vec<CosmosFeeToken>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CosmosFeeToken {
+    pub denom: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_min_gas_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub low_gas_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_gas_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high_gas_price: Option<f64>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CosmosApis {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rpc: Vec<CosmosEndpoint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rest: Vec<CosmosEndpoint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grpc: Vec<CosmosEndpoint>,
+    #[serde(
+        rename = "evm-http-jsonrpc",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub evm_http_jsonrpc: Vec<CosmosEndpoint>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CosmosEndpoint {
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CosmosExplorer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator_page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposal_page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_page: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CosmosAssetList {
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub chain_name: String,
+    pub assets: Vec<CosmosAsset>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CosmosAsset {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub denom_units: Vec<CosmosDenomUnit>,
+    pub type_asset: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub base: String,
+    pub name: String,
+    pub display: String,
+    pub symbol: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub traces: Vec<CosmosTrace>,
+    #[serde(rename = "logo_URIs", skip_serializing_if = "Option::is_none")]
+    pub logo_uris: Option<CosmosLogoUris>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<CosmosLogoUris>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coingecko_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CosmosDenomUnit {
+    pub denom: String,
+    pub exponent: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// A chain-registry trace. `type` is one of `ibc`, `bridge`, `wrapped`, `liquid-stake`, etc.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CosmosTrace {
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub counterparty: CosmosTraceCounterparty,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<CosmosTraceChain>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CosmosTraceCounterparty {
+    pub chain_name: String,
+    pub base_denom: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CosmosTraceChain {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl From<&Chain> for Converted<CosmosChain> {
+    fn from(chain: &Chain) -> Self {
+        let mut lossy = Vec::new();
+
+        let metadata = &chain.metadata;
+        for (field, set) in [
+            ("op_bridge_id", metadata.op_bridge_id.is_some()),
+            ("op_denoms", !metadata.op_denoms.is_empty()),
+            ("executor_uri", metadata.executor_uri.is_some()),
+            ("assetlist", metadata.assetlist.is_some()),
+            ("is_l1", metadata.is_l1.is_some()),
+            ("ibc_channels", !metadata.ibc_channels.is_empty()),
+            ("minitia", metadata.minitia.is_some()),
+        ] {
+            if set {
+                lossy.push(format!("$.metadata.{field}"));
+            }
+        }
+        if chain.evm_chain_id.is_some() {
+            lossy.push("$.evm_chain_id".to_string());
+        }
+
+        let apis = &chain.apis;
+        for (field, endpoints) in [
+            ("api", &apis.api),
+            ("json-rpc-websocket", &apis.json_rpc_websocket),
+            ("indexer", &apis.indexer),
+        ] {
+            if !endpoints.is_empty() {
+                lossy.push(format!("$.apis.{field}"));
+            }
+        }
+        let mut endpoints = |field: &str, endpoints: &[Endpoint]| {
+            endpoints
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    if e.authorized_user.is_some() {
+                        lossy.push(format!("$.apis.{field}[{i}].authorizedUser"));
+                    }
+                    CosmosEndpoint {
+                        address: e.address.clone(),
+                        provider: e.provider.clone(),
+                        extra: BTreeMap::new(),
+                    }
+                })
+                .collect()
+        };
+        let apis = CosmosApis {
+            rpc: endpoints("rpc", &apis.rpc),
+            rest: endpoints("rest", &apis.rest),
+            grpc: endpoints("grpc", &apis.grpc),
+            evm_http_jsonrpc: endpoints("json-rpc", &apis.json_rpc),
+            extra: BTreeMap::new(),
+        };
+
+        let explorers = chain
+            .explorers
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                if e.evm_tx_page.is_some() {
+                    lossy.push(format!("$.explorers[{i}].evm_tx_page"));
+                }
+                CosmosExplorer {
+                    kind: Some(e.kind.clone()),
+                    url: Some(e.url.clone()),
+                    tx_page: Some(e.tx_page.clone()),
+                    account_page: Some(e.account_page.clone()),
+                    validator_page: e.validator_page.clone(),
+                    proposal_page: e.proposal_page.clone(),
+                    block_page: e.block_page.clone(),
+                    extra: BTreeMap::new(),
+                }
+            })
+            .collect();
+
+        let key_algos = match chain.key_algorithm() {
+            KeyAlgorithm::Secp256k1 => vec![],
+            KeyAlgorithm::EthSecp256k1 => vec!["ethsecp256k1".to_string()],
+        };
+
+        Converted {
+            value: CosmosChain {
+                schema: Some("../chain.schema.json".to_string()),
+                chain_name: chain.chain_name.clone(),
+                status: None,
+                network_type: chain.network_type.clone(),
+                website: chain.website.clone(),
+                pretty_name: Some(chain.pretty_name.clone()),
+                description: chain.description.clone(),
+                chain_type: "cosmos".to_string(),
+                chain_id: chain.chain_id.clone(),
+                bech32_prefix: chain.bech32_prefix.clone(),
+                slip44: Some(chain.slip44),
+                key_algos,
+                fees: Some(CosmosFees {
+                    fee_tokens: chain
+                        .fees
+                        .fee_tokens
+                        .iter()
+                        .map(|t| CosmosFeeToken {
+                            denom: t.denom.clone(),
+                            fixed_min_gas_price: t.fixed_min_gas_price,
+                            low_gas_price: t.low_gas_price,
+                            average_gas_price: t.average_gas_price,
+                            high_gas_price: t.high_gas_price,
+                            extra: BTreeMap::new(),
+                        })
+                        .collect(),
+                    extra: BTreeMap::new(),
+                }),
+                apis: Some(apis),
+                explorers,
+                logo_uris: Some((&chain.logo_uris).into()),
+                extra: BTreeMap::new(),
+            },
+            lossy,
+        }
+    }
+}
+
+impl TryFrom<&CosmosChain> for Converted<Chain> {
+    type Error = CosmosError;
+
+    fn try_from(chain: &CosmosChain) -> Result<Self, Self::Error> {
+        let mut lossy = Vec::new();
+
+        let slip44 = chain.slip44.ok_or_else(|| CosmosError::MissingSlip44 {
+            chain_name: chain.chain_name.clone(),
+        })?;
+        let logo_uris = logo(chain.logo_uris.as_ref(), &[], "$", &mut lossy)?;
+
+        if chain.status.is_some() {
+            lossy.push("$.status".to_string());
+        }
+        report_extra(&chain.extra, "$", &mut lossy);
+
+        let fee_tokens = match &chain.fees {
+            Some(fees) => {
+                report_extra(&fees.extra, "$.fees", &mut lossy);
+                fees.fee_tokens
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| {
+                        report_extra(&t.extra, &format!("$.fees.fee_tokens[{i}]"), &mut lossy);
+                        FeeToken {
+                            denom: t.denom.clone(),
+                            fixed_min_gas_price: t.fixed_min_gas_price,
+                            low_gas_price: t.low_gas_price,
+                            average_gas_price: t.average_gas_price,
+                            high_gas_price: t.high_gas_price,
+                        }
+                    })
+                    .collect()
+            }
+            None => vec![],
+        };
+
+        let apis = chain.apis.clone().unwrap_or_default();
+        report_extra(&apis.extra, "$.apis", &mut lossy);
+        let mut endpoints = |field: &str, endpoints: &[CosmosEndpoint]| {
+            endpoints
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    report_extra(&e.extra, &format!("$.apis.{field}[{i}]"), &mut lossy);
+                    Endpoint {
+                        address: e.address.clone(),
+                        provider: e.provider.clone(),
+                        authorized_user: None,
+                    }
+                })
+                .collect()
+        };
+        let apis = Apis {
+            rpc: endpoints("rpc", &apis.rpc),
+            rest: endpoints("rest", &apis.rest),
+            api: vec![],
+            grpc: endpoints("grpc", &apis.grpc),
+            json_rpc: endpoints("evm-http-jsonrpc", &apis.evm_http_jsonrpc),
+            json_rpc_websocket: vec![],
+            indexer: vec![],
+        };
+
+        let mut explorers = Vec::new();
+        for (i, e) in chain.explorers.iter().enumerate() {
+            let (Some(kind), Some(url), Some(tx_page), Some(account_page)) =
+                (&e.kind, &e.url, &e.tx_page, &e.account_page)
+            else {
+                lossy.push(format!("$.explorers[{i}]"));
+                continue;
+            };
+            report_extra(&e.extra, &format!("$.explorers[{i}]"), &mut lossy);
+            explorers.push(Explorer {
+                kind: kind.clone(),
+                url: url.clone(),
+                tx_page: tx_page.clone(),
+                account_page: account_page.clone(),
+                block_page: e.block_page.clone(),
+                validator_page: e.validator_page.clone(),
+                proposal_page: e.proposal_page.clone(),
+                evm_tx_page: None,
+            });
+        }
+
+        let value = Chain {
+            chain_id: chain.chain_id.clone(),
+            chain_name: chain.chain_name.clone(),
+            pretty_name: chain
+                .pretty_name
+                .clone()
+                .unwrap_or_else(|| chain.chain_name.clone()),
+            description: chain.description.clone(),
+            website: chain.website.clone(),
+            fees: Fees { fee_tokens },
+            apis,
+            explorers,
+            metadata: Metadata {
+                op_bridge_id: None,
+                op_denoms: vec![],
+                executor_uri: None,
+                assetlist: None,
+                is_l1: None,
+                ibc_channels: vec![],
+                minitia: None,
+            },
+            logo_uris,
+            slip44,
+            bech32_prefix: chain.bech32_prefix.clone(),
+            network_type: chain.network_type.clone(),
+            evm_chain_id: None,
+        };
+        if key_algorithm(&chain.key_algos) != Some(value.key_algorithm()) {
+            lossy.push("$.key_algos".to_string());
+        }
+        Ok(Converted { value, lossy })
+    }
+}
+
+impl From<&Asset> for Converted<CosmosAsset> {
+    fn from(asset: &Asset) -> Self {
+        let mut lossy = Vec::new();
+        let value = asset_to_cosmos(asset, "$", &mut lossy);
+        Converted { value, lossy }
+    }
+}
+
+impl TryFrom<&CosmosAsset> for Converted<Asset> {
+    type Error = CosmosError;
+
+    fn try_from(asset: &CosmosAsset) -> Result<Self, Self::Error> {
+        let mut lossy = Vec::new();
+        let value = asset_from_cosmos(asset, "$", &mut lossy)?;
+        Ok(Converted { value, lossy })
+    }
+}
+
+impl From<&AssetList> for Converted<CosmosAssetList> {
+    fn from(list: &AssetList) -> Self {
+        let mut lossy = Vec::new();
+        let assets = list
+            .assets
+            .iter()
+            .enumerate()
+            .map(|(i, a)| asset_to_cosmos(a, &format!("$.assets[{i}]"), &mut lossy))
+            .collect();
+        Converted {
+            value: CosmosAssetList {
+                schema: Some("../assetlist.schema.json".to_string()),
+                chain_name: list.chain_name.clone(),
+                assets,
+                extra: BTreeMap::new(),
+            },
+            lossy,
+        }
+    }
+}
+
+impl TryFrom<&CosmosAssetList> for Converted<AssetList> {
+    type Error = CosmosError;
+
+    fn try_from(list: &CosmosAssetList) -> Result<Self, Self::Error> {
+        let mut lossy = Vec::new();
+        report_extra(&list.extra, "$", &mut lossy);
+        let assets = list
+            .assets
+            .iter()
+            .enumerate()
+            .map(|(i, a)| asset_from_cosmos(a, &format!("$.assets[{i}]"), &mut lossy))
+            .collect::<Result<_, _>>()?;
+        Ok(Converted {
+            value: AssetList {
+                schema: "../../assetlist.schema.json".to_string(),
+                chain_name: list.chain_name.clone(),
+                assets,
+            },
+            lossy,
+        })
+    }
+}
+
+fn asset_to_cosmos(asset: &Asset, path: &str, lossy: &mut Vec<String>) -> CosmosAsset {
+    let type_asset = asset.type_asset.clone().unwrap_or_else(|| {
+        if asset.base.starts_with(EVM_PREFIX) {
+            "erc20"
+        } else if asset.base.starts_with(IBC_PREFIX) {
+            "ics20"
+        } else {
+            "sdk.coin"
+        }
+        .to_string()
+    });
+
+    let traces = asset
+        .traces
+        .iter()
+        .enumerate()
+        .map(|(i, trace)| match trace {
+            Trace::Op {
+                counterparty,
+                chain,
+            } => {
+                if !chain.bridge_id.is_empty() {
+                    lossy.push(format!("{path}.traces[{i}].chain.bridge_id"));
+                }
+                CosmosTrace {
+                    ty: "bridge".to_string(),
+                    counterparty: CosmosTraceCounterparty {
+                        chain_name: counterparty.chain_name.clone(),
+                        base_denom: counterparty.base_denom.clone(),
+                        channel_id: None,
+                        extra: BTreeMap::new(),
+                    },
+                    chain: None,
+                    provider: Some(OPINIT_PROVIDER.to_string()),
+                    extra: BTreeMap::new(),
+                }
+            }
+            Trace::Ibc {
+                counterparty,
+                chain,
+            } => CosmosTrace {
+                ty: "ibc".to_string(),
+                counterparty: CosmosTraceCounterparty {
+                    chain_name: counterparty.chain_name.clone(),
+                    base_denom: counterparty.base_denom.clone(),
+                    channel_id: Some(counterparty.channel_id.clone()),
+                    extra: BTreeMap::new(),
+                },
+                chain: Some(CosmosTraceChain {
+                    channel_id: Some(chain.channel_id.clone()),
+                    path: Some(chain.path.clone()),
+                    contract: None,
+                    extra: BTreeMap::new(),
+                }),
+                provider: None,
+                extra: BTreeMap::new(),
+            },
+            Trace::Wrapped {
+                counterparty,
+                chain,
+                provider,
+            } => CosmosTrace {
+                ty: "wrapped".to_string(),
+                counterparty: CosmosTraceCounterparty {
+                    chain_name: counterparty.chain_name.clone(),
+                    base_denom: counterparty.base_denom.clone(),
+                    channel_id: None,
+                    extra: BTreeMap::new(),
+                },
+                chain: Some(CosmosTraceChain {
+                    contract: Some(chain.contract.clone()),
+                    ..Default::default()
+                }),
+                provider: Some(provider.clone()),
+                extra: BTreeMap::new(),
+            },
+        })
+        .collect();
+
+    CosmosAsset {
+        description: Some(asset.description.clone()),
+        denom_units: asset
+            .denom_units
+            .iter()
+            .map(|u| CosmosDenomUnit {
+                denom: u.denom.clone(),
+                exponent: u.exponent,
+                aliases: vec![],
+                extra: BTreeMap::new(),
+            })
+            .collect(),
+        type_asset,
+        address: evm_address(&asset.base),
+        base: asset.base.clone(),
+        name: asset.name.clone(),
+        display: asset.display.clone(),
+        symbol: asset.symbol.clone(),
+        traces,
+        logo_uris: Some((&asset.logo_uris).into()),
+        images: asset.images.iter().map(CosmosLogoUris::from).collect(),
+        coingecko_id: asset.coingecko_id.clone(),
+        extra: BTreeMap::new(),
+    }
+}
+
+fn asset_from_cosmos(
+    asset: &CosmosAsset,
+    path: &str,
+    lossy: &mut Vec<String>,
+) -> Result<Asset, CosmosError> {
+    if let Some(address) = &asset.address
+        && !evm_address(&asset.base).is_some_and(|a| a.eq_ignore_ascii_case(address))
+    {
+        lossy.push(format!("{path}.address"));
+    }
+    report_extra(&asset.extra, path, lossy);
+    let logo_uris = logo(asset.logo_uris.as_ref(), &asset.images, path, lossy)?;
+    for (i, image) in asset.images.iter().enumerate() {
+        report_extra(&image.extra, &format!("{path}.images[{i}]"), lossy);
+    }
+
+    let denom_units = asset
+        .denom_units
+        .iter()
+        .enumerate()
+        .map(|(i, u)| {
+            if !u.aliases.is_empty() {
+                lossy.push(format!("{path}.denom_units[{i}].aliases"));
+            }
+            report_extra(&u.extra, &format!("{path}.denom_units[{i}]"), lossy);
+            DenomUnit {
+                denom: u.denom.clone(),
+                exponent: u.exponent,
+            }
+        })
+        .collect();
+
+    let mut traces = Vec::new();
+    for (i, trace) in asset.traces.iter().enumerate() {
+        let counterparty = &trace.counterparty;
+        let chain = trace.chain.clone().unwrap_or_default();
+        let converted = match (trace.ty.as_str(), trace.provider.as_deref()) {
+            ("ibc", _) => match (&counterparty.channel_id, chain.channel_id, chain.path) {
+                (Some(counterparty_channel), Some(channel_id), Some(path)) => Some(Trace::Ibc {
+                    counterparty: IbcCounterparty {
+                        chain_name: counterparty.chain_name.clone(),
+                        base_denom: counterparty.base_denom.clone(),
+                        channel_id: counterparty_channel.clone(),
+                    },
+                    chain: IbcTraceChain { channel_id, path },
+                }),
+                _ => None,
+            },
+            ("wrapped", Some(provider)) => chain.contract.map(|contract| Trace::Wrapped {
+                counterparty: WrappedCounterparty {
+                    chain_name: counterparty.chain_name.clone(),
+                    base_denom: counterparty.base_denom.clone(),
+                },
+                chain: WrappedChain { contract },
+                provider: provider.to_string(),
+            }),
+            // Chain-registry bridge traces carry no bridge id, so an OPinit trace comes back
+            // with an empty one.
+            ("bridge", Some(OPINIT_PROVIDER)) => {
+                lossy.push(format!("{path}.traces[{i}].chain.bridge_id"));
+                Some(Trace::Op {
+                    counterparty: OpCounterparty {
+                        chain_name: counterparty.chain_name.clone(),
+                        base_denom: counterparty.base_denom.clone(),
+                    },
+                    chain: OpChain {
+                        bridge_id: String::new(),
+                    },
+                })
+            }
+            _ => None,
+        };
+        let trace_path = format!("{path}.traces[{i}]");
+        match converted {
+            Some(converted) => {
+                report_extra(&trace.extra, &trace_path, lossy);
+                report_extra(
+                    &counterparty.extra,
+                    &format!("{trace_path}.counterparty"),
+                    lossy,
+                );
+                if let Some(chain) = &trace.chain {
+                    report_extra(&chain.extra, &format!("{trace_path}.chain"), lossy);
+                }
+                traces.push(converted);
+            }
+            None => lossy.push(trace_path),
+        }
+    }
+
+    Ok(Asset {
+        description: asset.description.clone().unwrap_or_default(),
+        denom_units,
+        base: asset.base.clone(),
+        display: asset.display.clone(),
+        name: asset.name.clone(),
+        symbol: asset.symbol.clone(),
+        coingecko_id: asset.coingecko_id.clone(),
+        type_asset: Some(asset.type_asset.clone()),
+        images: asset
+            .images
+            .iter()
+            .flat_map(CosmosLogoUris::images)
+            .collect(),
+        logo_uris,
+        traces,
+    })
+}
+
+/// The key algorithm declared by chain-registry `key_algos`, which default to secp256k1.
+fn key_algorithm(key_algos: &[String]) -> Option<KeyAlgorithm> {
+    match key_algos {
+        [] => Some(KeyAlgorithm::Secp256k1),
+        [algo] if algo == "secp256k1" => Some(KeyAlgorithm::Secp256k1),
+        [algo] if algo == "ethsecp256k1" => Some(KeyAlgorithm::EthSecp256k1),
+        _ => None,
+    }
+}
+
+/// The checksummed `0x` contract of an `evm/` denom, which chain-registry requires as the
+/// `address` of erc20 assets.
+fn evm_address(base: &str) -> Option<String> {
+    let contract = base.strip_prefix(EVM_PREFIX)?;
+    let bytes = decode_hex(&format!("0x{contract}")).ok()?;
+    Some(format!("0x{}", eip55_checksum(&bytes)))
+}
+
+fn report_extra(extra: &BTreeMap<String, Value>, path: &str, lossy: &mut Vec<String>) {
+    lossy.extend(extra.keys().map(|key| format!("{path}.{key}")));
+}
+
+/// Picks the single Initia logo from `logo_URIs`, falling back to the first image. A PNG wins
+/// over an SVG, which is then reported as lossy.
+fn logo(
+    logo_uris: Option<&CosmosLogoUris>,
+    images: &[CosmosLogoUris],
+    path: &str,
+    lossy: &mut Vec<String>,
+) -> Result<ImageType, CosmosError> {
+    if let Some(uris) = logo_uris {
+        report_extra(&uris.extra, &format!("{path}.logo_URIs"), lossy);
+        let mut all = uris.images().into_iter();
+        if let Some(first) = all.next() {
+            if all.next().is_some() {
+                lossy.push(format!("{path}.logo_URIs.svg"));
+            }
+            return Ok(first);
+        }
+    }
+    images
+        .iter()
+        .find_map(|image| image.images().into_iter().next())
+        .ok_or_else(|| CosmosError::MissingLogo {
+            path: path.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAIN: &str = r#"{
+        "chain_id": "yominet-1",
+        "chain_name": "yominet",
+        "pretty_name": "Yominet",
+        "fees": {"fee_tokens": [{"denom": "uinit", "average_gas_price": 0.015}]},
+        "apis": {
+            "rpc": [
+                {"address": "https://rpc.yominet.xyz", "provider": "Initia Labs"},
+                {"address": "https://rpc-skip.yominet.xyz", "authorizedUser": "skip"}
+            ],
+            "json-rpc": [{"address": "https://jsonrpc.yominet.xyz"}],
+            "indexer": [{"address": "https://indexer.yominet.xyz"}]
+        },
+        "explorers": [
+            {
+                "kind": "initia scan",
+                "url": "https://scan.initia.xyz/yominet-1",
+                "tx_page": "https://scan.initia.xyz/yominet-1/txs/${txHash}",
+                "account_page": "https://scan.initia.xyz/yominet-1/accounts/${accountAddress}"
+            }
+        ],
+        "metadata": {
+            "op_bridge_id": "1",
+            "minitia": {"type": "minievm", "version": "v1.0.0"}
+        },
+        "logo_URIs": {"png": "https://registry.initia.xyz/images/yominet.png"},
+        "slip44": 60,
+        "bech32_prefix": "init",
+        "network_type": "mainnet",
+        "evm_chain_id": 428962654539583
+    }"#;
+
+    #[test]
+    fn test_chain_to_cosmos() {
+        let chain: Chain = serde_json::from_str(CHAIN).expect("Failed to deserialize chain");
+        let converted = Converted::<CosmosChain>::from(&chain);
+
+        assert_eq!(
+            converted.lossy,
+            vec![
+                "$.metadata.op_bridge_id",
+                "$.metadata.minitia",
+                "$.evm_chain_id",
+                "$.apis.indexer",
+                "$.apis.rpc[1].authorizedUser",
+            ]
+        );
+        let value = serde_json::to_value(&converted.value).unwrap();
+        assert_eq!(value["chain_type"], "cosmos");
+        assert_eq!(value["key_algos"], serde_json::json!(["ethsecp256k1"]));
+        assert_eq!(
+            value["apis"]["evm-http-jsonrpc"][0]["address"],
+            "https://jsonrpc.yominet.xyz"
+        );
+        assert_eq!(
+            value["logo_URIs"],
+            serde_json::json!({"png": "https://registry.initia.xyz/images/yominet.png"})
+        );
+
+        let back = Converted::<Chain>::try_from(&converted.value).unwrap();
+        assert!(back.is_lossless());
+        assert_eq!(back.value.apis.rpc.len(), 2);
+        assert_eq!(back.value.explorers, chain.explorers);
+        assert_eq!(back.value.fees, chain.fees);
+    }
+
+    #[test]
+    fn test_chain_from_cosmos() {
+        let cosmos: CosmosChain = serde_json::from_str(
+            r#"{
+                "chain_name": "osmosis",
+                "status": "live",
+                "network_type": "mainnet",
+                "chain_type": "cosmos",
+                "chain_id": "osmosis-1",
+                "bech32_prefix": "osmo",
+                "slip44": 118,
+                "explorers": [
+                    {"kind": "mintscan", "url": "https://www.mintscan.io/osmosis"},
+                    {
+                        "kind": "ping.pub",
+                        "url": "https://ping.pub/osmosis",
+                        "tx_page": "https://ping.pub/osmosis/tx/${txHash}",
+                        "account_page": "https://ping.pub/osmosis/account/${accountAddress}"
+                    }
+                ],
+                "logo_URIs": {
+                    "png": "https://raw.githubusercontent.com/cosmos/chain-registry/master/osmosis/images/osmo.png",
+                    "svg": "https://raw.githubusercontent.com/cosmos/chain-registry/master/osmosis/images/osmo.svg"
+                }
+            }"#,
+        )
+        .expect("Failed to deserialize cosmos chain");
+
+        let converted = Converted::<Chain>::try_from(&cosmos).unwrap();
+        assert_eq!(
+            converted.lossy,
+            vec!["$.logo_URIs.svg", "$.status", "$.explorers[0]"]
+        );
+        let chain = converted.value;
+        assert_eq!(chain.pretty_name, "osmosis");
+        assert_eq!(chain.explorers.len(), 1);
+        assert!(matches!(chain.logo_uris, ImageType::PNG(_)));
+        assert!(chain.fees.fee_tokens.is_empty());
+
+        let mut cosmos = cosmos;
+        cosmos.slip44 = None;
+        assert_eq!(
+            Converted::<Chain>::try_from(&cosmos),
+            Err(CosmosError::MissingSlip44 {
+                chain_name: "osmosis".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_chain_from_cosmos_reports_unmodelled_fields() {
+        let osmosis: CosmosChain = serde_json::from_str(
+            r##"{
+                "$schema": "../chain.schema.json",
+                "chain_name": "osmosis",
+                "status": "live",
+                "website": "https://osmosis.zone/",
+                "network_type": "mainnet",
+                "pretty_name": "Osmosis",
+                "chain_type": "cosmos",
+                "chain_id": "osmosis-1",
+                "bech32_prefix": "osmo",
+                "daemon_name": "osmosisd",
+                "node_home": "$HOME/.osmosisd",
+                "key_algos": ["secp256k1"],
+                "slip44": 118,
+                "fees": {
+                    "fee_tokens": [
+                        {
+                            "denom": "uosmo",
+                            "fixed_min_gas_price": 0.0025,
+                            "low_gas_price": 0.0025,
+                            "average_gas_price": 0.025,
+                            "high_gas_price": 0.04,
+                            "gas_costs": {"cosmos_send": 100000}
+                        }
+                    ]
+                },
+                "staking": {"staking_tokens": [{"denom": "uosmo"}]},
+                "codebase": {
+                    "git_repo": "https://github.com/osmosis-labs/osmosis",
+                    "recommended_version": "v25.0.0",
+                    "compatible_versions": ["v25.0.0"]
+                },
+                "apis": {
+                    "rpc": [{"address": "https://rpc.osmosis.zone/", "provider": "Osmosis Foundation"}],
+                    "rest": [{"address": "https://lcd.osmosis.zone/", "provider": "Osmosis Foundation", "archive": true}],
+                    "grpc-web": [{"address": "https://grpc-web.osmosis.zone/", "provider": "Osmosis Foundation"}]
+                },
+                "explorers": [
+                    {
+                        "kind": "mintscan",
+                        "url": "https://www.mintscan.io/osmosis",
+                        "tx_page": "https://www.mintscan.io/osmosis/transactions/${txHash}",
+                        "account_page": "https://www.mintscan.io/osmosis/accounts/${accountAddress}"
+                    }
+                ],
+                "logo_URIs": {
+                    "png": "https://raw.githubusercontent.com/cosmos/chain-registry/master/osmosis/images/osmo.png",
+                    "theme": {"primary_color_hex": "#760dbb"}
+                },
+                "keywords": ["dex"]
+            }"##,
+        )
+        .expect("Failed to deserialize osmosis chain");
+
+        let converted = Converted::<Chain>::try_from(&osmosis).unwrap();
+        assert_eq!(
+            converted.lossy,
+            vec![
+                "$.logo_URIs.theme",
+                "$.status",
+                "$.codebase",
+                "$.daemon_name",
+                "$.keywords",
+                "$.node_home",
+                "$.staking",
+                "$.fees.fee_tokens[0].gas_costs",
+                "$.apis.grpc-web",
+                "$.apis.rest[0].archive",
+            ]
+        );
+        assert_eq!(
+            converted.value.fees.fee_tokens[0].high_gas_price,
+            Some(0.04)
+        );
+
+        let exported = Converted::<CosmosChain>::from(&converted.value).value;
+        assert!(exported.extra.is_empty());
+
+        let mut osmosis = osmosis;
+        osmosis.key_algos = vec!["ethsecp256k1".to_string()];
+        let converted = Converted::<Chain>::try_from(&osmosis).unwrap();
+        assert_eq!(converted.lossy.last().unwrap(), "$.key_algos");
+    }
+
+    #[test]
+    fn test_asset_roundtrip() {
+        let asset: Asset = serde_json::from_str(
+            r#"{
+                "description": "USDC on Yominet",
+                "denom_units": [
+                    {"denom": "l2/2588fd87a8e081f6a557f43ff14f05dddf5e34cb27afcefd6eaf81f1daea30d0", "exponent": 0},
+                    {"denom": "USDC", "exponent": 6}
+                ],
+                "base": "l2/2588fd87a8e081f6a557f43ff14f05dddf5e34cb27afcefd6eaf81f1daea30d0",
+                "display": "USDC",
+                "name": "USDC",
+                "symbol": "USDC",
+                "logo_URIs": {"svg": "https://registry.initia.xyz/images/USDC.svg"},
+                "images": [{"svg": "https://registry.initia.xyz/images/USDC.svg"}],
+                "traces": [
+                    {
+                        "type": "ibc",
+                        "counterparty": {"chain_name": "noble", "base_denom": "uusdc", "channel_id": "channel-129"},
+                        "chain": {"channel_id": "channel-3", "path": "transfer/channel-3/uusdc"}
+                    },
+                    {
+                        "type": "op",
+                        "counterparty": {"chain_name": "initia", "base_denom": "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4"},
+                        "chain": {"bridge_id": "28"}
+                    }
+                ]
+            }"#,
+        )
+        .expect("Failed to deserialize asset");
+
+        let converted = Converted::<CosmosAsset>::from(&asset);
+        assert_eq!(converted.lossy, vec!["$.traces[1].chain.bridge_id"]);
+        let value = serde_json::to_value(&converted.value).unwrap();
+        assert_eq!(value["type_asset"], "sdk.coin");
+        assert_eq!(value["traces"][1]["type"], "bridge");
+        assert_eq!(value["traces"][1]["provider"], OPINIT_PROVIDER);
+        assert_eq!(
+            value["traces"][0]["chain"]["path"],
+            "transfer/channel-3/uusdc"
+        );
+
+        let back = Converted::<Asset>::try_from(&converted.value).unwrap();
+        assert_eq!(back.lossy, vec!["$.traces[1].chain.bridge_id"]);
+        let mut expected = asset.clone();
+        expected.type_asset = Some("sdk.coin".to_string());
+        if let Trace::Op { chain, .. } = &mut expected.traces[1] {
+            chain.bridge_id = String::new();
+        }
+        assert_eq!(back.value, expected);
+    }
+
+    #[test]
+    fn test_evm_asset_address() {
+        let asset: Asset = serde_json::from_str(
+            r#"{
+                "description": "USDC on Yominet",
+                "denom_units": [
+                    {"denom": "evm/E1Ff7038eAAAF027031688E1535a055B2Bac2546", "exponent": 0},
+                    {"denom": "USDC", "exponent": 6}
+                ],
+                "base": "evm/E1Ff7038eAAAF027031688E1535a055B2Bac2546",
+                "display": "USDC",
+                "name": "USDC",
+                "symbol": "USDC",
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/USDC.png"}
+            }"#,
+        )
+        .expect("Failed to deserialize asset");
+
+        let converted = Converted::<CosmosAsset>::from(&asset);
+        assert!(converted.is_lossless());
+        assert_eq!(converted.value.type_asset, "erc20");
+        assert_eq!(
+            converted.value.address.as_deref(),
+            Some("0xE1Ff7038eAAAF027031688E1535a055B2Bac2546")
+        );
+
+        let mut cosmos = converted.value;
+        assert!(Converted::<Asset>::try_from(&cosmos).unwrap().is_lossless());
+        cosmos.address = Some("0x0000000000000000000000000000000000000001".to_string());
+        assert_eq!(
+            Converted::<Asset>::try_from(&cosmos).unwrap().lossy,
+            vec!["$.address"]
+        );
+    }
+
+    #[test]
+    fn test_asset_from_cosmos() {
+        let list: CosmosAssetList = serde_json::from_str(
+            r#"{
+                "$schema": "../assetlist.schema.json",
+                "chain_name": "osmosis",
+                "assets": [
+                    {
+                        "denom_units": [
+                            {"denom": "uosmo", "exponent": 0, "aliases": ["micro-osmo"]},
+                            {"denom": "osmo", "exponent": 6}
+                        ],
+                        "type_asset": "sdk.coin",
+                        "base": "uosmo",
+                        "name": "Osmosis",
+                        "display": "osmo",
+                        "symbol": "OSMO",
+                        "traces": [
+                            {
+                                "type": "liquid-stake",
+                                "counterparty": {"chain_name": "stride", "base_denom": "stuosmo"},
+                                "provider": "Stride"
+                            }
+                        ],
+                        "images": [{"png": "https://raw.githubusercontent.com/cosmos/chain-registry/master/osmosis/images/osmo.png"}]
+                    },
+                    {
+                        "denom_units": [{"denom": "unologo", "exponent": 0}],
+                        "type_asset": "sdk.coin",
+                        "base": "unologo",
+                        "name": "No Logo",
+                        "display": "unologo",
+                        "symbol": "NOLOGO"
+                    }
+                ]
+            }"#,
+        )
+        .expect("Failed to deserialize cosmos assetlist");
+
+        assert_eq!(
+            Converted::<AssetList>::try_from(&list),
+            Err(CosmosError::MissingLogo {
+                path: "$.assets[1]".to_string()
+            })
+        );
+
+        let mut list = list;
+        list.assets.truncate(1);
+        let converted = Converted::<AssetList>::try_from(&list).unwrap();
+        assert_eq!(
+            converted.lossy,
+            vec![
+                "$.assets[0].denom_units[0].aliases",
+                "$.assets[0].traces[0]"
+            ]
+        );
+        let asset = &converted.value.assets[0];
+        assert!(asset.traces.is_empty());
+        assert_eq!(asset.description, "");
+        assert_eq!(asset.logo_uris, asset.images[0]);
+
+        list.assets[0].logo_uris =
+            Some(serde_json::from_str(r##"{"theme": {"primary_color_hex": "#760dbb"}}"##).unwrap());
+        let converted = Converted::<AssetList>::try_from(&list).unwrap();
+        assert!(
+            converted
+                .lossy
+                .contains(&"$.assets[0].logo_URIs.theme".to_string())
+        );
+        let asset = &converted.value.assets[0];
+        assert_eq!(asset.logo_uris, asset.images[0]);
+    }
+}
@@ -0,0 +1,354 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use sha2::{Digest, Sha256};
+use sha3::Sha3_256;
+
+use crate::{
+    asset::{Asset, IbcTraceChain},
+    chain::{Chain, FeeToken},
+    common::eip55_checksum,
+};
+
+/// A bank denom classified by its prefix.
+///
+/// Parsing only accepts the canonical spelling of each kind (uppercase IBC hashes, lowercase
+/// `l2/` and `move/` hex, EIP-55 checksummed `evm/` addresses) so that `Display` reproduces the
+/// input exactly.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Denom {
+    Ibc { hash: [u8; 32] },
+    L2 { hash: [u8; 32] },
+    Evm { contract: [u8; 20] },
+    Move { metadata: [u8; 32] },
+    Native(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DenomError {
+    Empty,
+    InvalidHex { denom: String },
+    InvalidLength { denom: String, expected: usize },
+    NonCanonical { denom: String, canonical: String },
+}
+
+impl fmt::Display for DenomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DenomError::Empty => f.write_str("denom is empty"),
+            DenomError::InvalidHex { denom } => write!(f, "denom `{denom}` has invalid hex"),
+            DenomError::InvalidLength { denom, expected } => {
+                write!(f, "denom `{denom}` must carry {expected} bytes")
+            }
+            DenomError::NonCanonical { denom, canonical } => {
+                write!(
+                    f,
+                    "denom `{denom}` is not canonical, expected `{canonical}`"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DenomError {}
+
+pub const IBC_PREFIX: &str = "ibc/";
+pub const L2_PREFIX: &str = "l2/";
+pub const EVM_PREFIX: &str = "evm/";
+pub const MOVE_PREFIX: &str = "move/";
+
+impl FromStr for Denom {
+    type Err = DenomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let denom = if let Some(hex) = s.strip_prefix(IBC_PREFIX) {
+            Denom::Ibc {
+                hash: decode_hex(s, hex)?,
+            }
+        } else if let Some(hex) = s.strip_prefix(L2_PREFIX) {
+            Denom::L2 {
+                hash: decode_hex(s, hex)?,
+            }
+        } else if let Some(hex) = s.strip_prefix(EVM_PREFIX) {
+            Denom::Evm {
+                contract: decode_hex(s, hex)?,
+            }
+        } else if let Some(hex) = s.strip_prefix(MOVE_PREFIX) {
+            Denom::Move {
+                metadata: decode_hex(s, hex)?,
+            }
+        } else if s.is_empty() {
+            return Err(DenomError::Empty);
+        } else {
+            return Ok(Denom::Native(s.to_string()));
+        };
+
+        let canonical = denom.to_string();
+        if canonical != s {
+            return Err(DenomError::NonCanonical {
+                denom: s.to_string(),
+                canonical,
+            });
+        }
+        Ok(denom)
+    }
+}
+
+impl fmt::Display for Denom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Denom::Ibc { hash } => write!(f, "{IBC_PREFIX}{}", hex::encode_upper(hash)),
+            Denom::L2 { hash } => write!(f, "{L2_PREFIX}{}", hex::encode(hash)),
+            Denom::Evm { contract } => write!(f, "{EVM_PREFIX}{}", eip55_checksum(contract)),
+            Denom::Move { metadata } => write!(f, "{MOVE_PREFIX}{}", hex::encode(metadata)),
+            Denom::Native(denom) => f.write_str(denom),
+        }
+    }
+}
+
+impl Denom {
+    /// Derives the `ibc/<SHA256>` voucher denom for a full denom trace path, e.g.
+    /// `transfer/channel-3/uusdc` or the multi-hop
+    /// `transfer/channel-1/transfer/channel-7/uatom`.
+    pub fn ibc_from_path(path: &str) -> Denom {
+        Denom::Ibc {
+            hash: Sha256::digest(path.as_bytes()).into(),
+        }
+    }
+
+    /// Derives the `l2/<hex>` denom OPinit mints on the L2 for `l1_denom` deposited through
+    /// `bridge_id`, i.e. `SHA3-256(big_endian(bridge_id) || l1_denom)`.
+    pub fn l2_from_bridge(bridge_id: u64, l1_denom: &str) -> Denom {
+        let mut hasher = Sha3_256::new();
+        hasher.update(bridge_id.to_be_bytes());
+        hasher.update(l1_denom.as_bytes());
+        Denom::L2 {
+            hash: hasher.finalize().into(),
+        }
+    }
+}
+
+/// Builds a denom trace path from `(port_id, channel_id)` hops, ordered from the receiving
+/// chain back towards the origin.
+pub fn ibc_path<'a>(
+    hops: impl IntoIterator<Item = (&'a str, &'a str)>,
+    base_denom: &str,
+) -> String {
+    let mut path = String::new();
+    for (port_id, channel_id) in hops {
+        path.push_str(port_id);
+        path.push('/');
+        path.push_str(channel_id);
+        path.push('/');
+    }
+    path.push_str(base_denom);
+    path
+}
+
+impl Chain {
+    /// Predicts the L2 denom of an OPinit deposit of `l1_denom` into this chain, or `None` when
+    /// the chain has no valid `op_bridge_id`.
+    pub fn op_l2_denom(&self, l1_denom: &str) -> Option<Denom> {
+        let bridge_id = self.metadata.op_bridge_id.as_ref()?.parse().ok()?;
+        Some(Denom::l2_from_bridge(bridge_id, l1_denom))
+    }
+}
+
+impl IbcTraceChain {
+    pub fn ibc_denom(&self) -> Denom {
+        Denom::ibc_from_path(&self.path)
+    }
+}
+
+impl Serialize for Denom {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Denom {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Asset {
+    pub fn parse_base(&self) -> Result<Denom, DenomError> {
+        self.base.parse()
+    }
+}
+
+impl FeeToken {
+    pub fn parse_denom(&self) -> Result<Denom, DenomError> {
+        self.denom.parse()
+    }
+}
+
+fn decode_hex<const N: usize>(denom: &str, hex: &str) -> Result<[u8; N], DenomError> {
+    let bytes = hex::decode(hex).map_err(|_| DenomError::InvalidHex {
+        denom: denom.to_string(),
+    })?;
+    bytes.try_into().map_err(|_| DenomError::InvalidLength {
+        denom: denom.to_string(),
+        expected: N,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IBC: &str = "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4";
+    const L2: &str = "l2/9d3d65bf3329e45ad659f9cbee7d6dc7b6246b001e32131a9b465215eab90562";
+    const EVM: &str = "evm/E1Ff7038eAAAF027031688E1535a055B2Bac2546";
+    const MOVE: &str = "move/edfcddacac79ab86737a1e9e65805066d8be286a37cb94f4884b892b0e39f954";
+
+    #[test]
+    fn test_parse_kinds() {
+        match IBC.parse::<Denom>().expect("Failed to parse ibc denom") {
+            Denom::Ibc { hash } => assert_eq!(hash[..2], [0x64, 0x90]),
+            other => panic!("Expected IBC denom, got {other:?}"),
+        }
+        match L2.parse::<Denom>().expect("Failed to parse l2 denom") {
+            Denom::L2 { hash } => assert_eq!(hash[..2], [0x9d, 0x3d]),
+            other => panic!("Expected L2 denom, got {other:?}"),
+        }
+        match EVM.parse::<Denom>().expect("Failed to parse evm denom") {
+            Denom::Evm { contract } => assert_eq!(contract[..2], [0xe1, 0xff]),
+            other => panic!("Expected EVM denom, got {other:?}"),
+        }
+        match MOVE.parse::<Denom>().expect("Failed to parse move denom") {
+            Denom::Move { metadata } => assert_eq!(metadata[..2], [0xed, 0xfc]),
+            other => panic!("Expected Move denom, got {other:?}"),
+        }
+        assert_eq!(
+            "uinit".parse::<Denom>(),
+            Ok(Denom::Native("uinit".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for denom in [IBC, L2, EVM, MOVE, "uinit", "factory/init1abc/utoken"] {
+            let parsed: Denom = denom.parse().expect("Failed to parse denom");
+            assert_eq!(parsed.to_string(), denom);
+        }
+    }
+
+    #[test]
+    fn test_reject_non_canonical() {
+        let lower_ibc = IBC.to_lowercase();
+        assert_eq!(
+            lower_ibc.parse::<Denom>(),
+            Err(DenomError::NonCanonical {
+                denom: lower_ibc.clone(),
+                canonical: IBC.to_string(),
+            })
+        );
+        assert!(matches!(
+            EVM.to_lowercase().parse::<Denom>(),
+            Err(DenomError::NonCanonical { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reject_malformed() {
+        assert_eq!("".parse::<Denom>(), Err(DenomError::Empty));
+        assert!(matches!(
+            "ibc/XYZ".parse::<Denom>(),
+            Err(DenomError::InvalidHex { .. })
+        ));
+        assert_eq!(
+            "evm/E1Ff".parse::<Denom>(),
+            Err(DenomError::InvalidLength {
+                denom: "evm/E1Ff".to_string(),
+                expected: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn test_ibc_from_path() {
+        assert_eq!(
+            Denom::ibc_from_path("transfer/channel-3/uusdc").to_string(),
+            IBC
+        );
+        assert_eq!(
+            Denom::ibc_from_path(
+                "transfer/channel-25/l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06"
+            )
+            .to_string(),
+            "ibc/07FFEEEE36370551554531FF7189EB2D3353B187CB6CE715DA1291C3A4B4EAC2"
+        );
+    }
+
+    #[test]
+    fn test_l2_from_bridge() {
+        assert_eq!(Denom::l2_from_bridge(30, "uinit").to_string(), L2);
+        assert_eq!(
+            Denom::l2_from_bridge(11, "uinit").to_string(),
+            "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06"
+        );
+        assert_ne!(
+            Denom::l2_from_bridge(11, "uinit"),
+            Denom::l2_from_bridge(12, "uinit")
+        );
+    }
+
+    #[test]
+    fn test_chain_op_l2_denom() {
+        let json = r#"{
+            "chain_id": "rena-nuwa-1",
+            "chain_name": "rena",
+            "pretty_name": "Rena",
+            "fees": {"fee_tokens": []},
+            "apis": {},
+            "explorers": [],
+            "metadata": {"op_bridge_id": "30", "op_denoms": ["uinit"]},
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/rena.png"},
+            "slip44": 60,
+            "bech32_prefix": "init",
+            "network_type": "mainnet"
+        }"#;
+        let mut chain: Chain = serde_json::from_str(json).expect("Failed to deserialize chain");
+
+        assert_eq!(
+            chain.op_l2_denom("uinit").map(|d| d.to_string()),
+            Some(L2.to_string())
+        );
+
+        chain.metadata.op_bridge_id = None;
+        assert_eq!(chain.op_l2_denom("uinit"), None);
+    }
+
+    #[test]
+    fn test_ibc_path() {
+        assert_eq!(
+            ibc_path([("transfer", "channel-3")], "uusdc"),
+            "transfer/channel-3/uusdc"
+        );
+        let multi_hop = ibc_path(
+            [("transfer", "channel-1"), ("transfer", "channel-7")],
+            "uatom",
+        );
+        assert_eq!(multi_hop, "transfer/channel-1/transfer/channel-7/uatom");
+        assert_ne!(
+            Denom::ibc_from_path(&multi_hop),
+            Denom::ibc_from_path("transfer/channel-1/uatom")
+        );
+        assert_eq!(ibc_path([], "uinit"), "uinit");
+    }
+
+    #[test]
+    fn test_serde_as_string() {
+        let denom: Denom =
+            serde_json::from_str(&format!("\"{EVM}\"")).expect("Failed to deserialize");
+        assert_eq!(
+            serde_json::to_string(&denom).expect("Failed to serialize"),
+            format!("\"{EVM}\"")
+        );
+        assert!(serde_json::from_str::<Denom>("\"ibc/zz\"").is_err());
+    }
+}
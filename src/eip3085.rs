@@ -0,0 +1,278 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{asset::AssetList, chain::Chain, registry::Registry};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Eip3085Error {
+    UnknownChain { chain_name: String },
+    MissingAssetlist { chain_name: String },
+    NotEvm { chain_name: String },
+    MissingJsonRpc { chain_name: String },
+    MissingFeeAsset { chain_name: String },
+    InvalidDecimals { denom: String, decimals: u32 },
+}
+
+impl fmt::Display for Eip3085Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Eip3085Error::UnknownChain { chain_name } => {
+                write!(f, "chain `{chain_name}` is not in the registry")
+            }
+            Eip3085Error::MissingAssetlist { chain_name } => {
+                write!(f, "chain `{chain_name}` has no assetlist")
+            }
+            Eip3085Error::NotEvm { chain_name } => {
+                write!(f, "chain `{chain_name}` has no `evm_chain_id`")
+            }
+            Eip3085Error::MissingJsonRpc { chain_name } => {
+                write!(
+                    f,
+                    "chain `{chain_name}` lists no public `json-rpc` endpoint"
+                )
+            }
+            Eip3085Error::MissingFeeAsset { chain_name } => write!(
+                f,
+                "chain `{chain_name}` has no fee token with a display unit in its assetlist"
+            ),
+            Eip3085Error::InvalidDecimals { denom, decimals } => {
+                write!(
+                    f,
+                    "`{denom}` has {decimals} decimals, more than fit in a u8"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Eip3085Error {}
+
+/// The `AddEthereumChainParameter` of `wallet_addEthereumChain`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddEthereumChainParameter {
+    /// `0x`-prefixed lowercase hex.
+    pub chain_id: String,
+    pub chain_name: String,
+    pub native_currency: NativeCurrency,
+    pub rpc_urls: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub block_explorer_urls: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub icon_urls: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NativeCurrency {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl AddEthereumChainParameter {
+    /// Builds the parameters from an EVM chain, taking the native currency from its first fee
+    /// token found in `assetlist`.
+    pub fn new(chain: &Chain, assetlist: &AssetList) -> Result<Self, Eip3085Error> {
+        let chain_id = chain.evm_chain_id.ok_or_else(|| Eip3085Error::NotEvm {
+            chain_name: chain.chain_name.clone(),
+        })?;
+
+        let rpc_urls: Vec<String> = chain
+            .apis
+            .json_rpc
+            .iter()
+            .filter(|e| e.authorized_user.is_none())
+            .map(|e| e.address.clone())
+            .collect();
+        if rpc_urls.is_empty() {
+            return Err(Eip3085Error::MissingJsonRpc {
+                chain_name: chain.chain_name.clone(),
+            });
+        }
+
+        let (asset, decimals) = chain
+            .fees
+            .fee_tokens
+            .iter()
+            .filter_map(|t| assetlist.assets.iter().find(|a| a.base == t.denom))
+            .find_map(|a| Some((a, a.decimals()?)))
+            .ok_or_else(|| Eip3085Error::MissingFeeAsset {
+                chain_name: chain.chain_name.clone(),
+            })?;
+        let native_currency = NativeCurrency {
+            name: asset.name.clone(),
+            symbol: asset.symbol.clone(),
+            decimals: decimals
+                .try_into()
+                .map_err(|_| Eip3085Error::InvalidDecimals {
+                    denom: asset.base.clone(),
+                    decimals,
+                })?,
+        };
+
+        Ok(Self {
+            chain_id: format!("{chain_id:#x}"),
+            chain_name: chain.pretty_name.clone(),
+            native_currency,
+            rpc_urls,
+            block_explorer_urls: chain.explorers.iter().map(|e| e.url.clone()).collect(),
+            icon_urls: vec![chain.logo_uris.url().to_string()],
+        })
+    }
+}
+
+impl Registry {
+    pub fn add_ethereum_chain_parameter(
+        &self,
+        chain_name: &str,
+    ) -> Result<AddEthereumChainParameter, Eip3085Error> {
+        let chain = self
+            .chain_by_name(chain_name)
+            .ok_or_else(|| Eip3085Error::UnknownChain {
+                chain_name: chain_name.to_string(),
+            })?;
+        let assetlist =
+            self.assetlist_for_chain(chain)
+                .ok_or_else(|| Eip3085Error::MissingAssetlist {
+                    chain_name: chain_name.to_string(),
+                })?;
+        AddEthereumChainParameter::new(chain, assetlist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain::ChainList, profile::ProfileList};
+
+    const CHAIN: &str = r#"{
+        "chain_id": "yominet-1",
+        "chain_name": "yominet",
+        "pretty_name": "Yominet",
+        "fees": {
+            "fee_tokens": [
+                {"denom": "uusdc"},
+                {"denom": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06"}
+            ]
+        },
+        "apis": {
+            "json-rpc": [
+                {"address": "https://jsonrpc-private.yominet.xyz", "authorizedUser": "skip"},
+                {"address": "https://jsonrpc.yominet.xyz"}
+            ]
+        },
+        "explorers": [
+            {
+                "kind": "initia scan",
+                "url": "https://scan.initia.xyz/yominet-1",
+                "tx_page": "https://scan.initia.xyz/yominet-1/txs/${txHash}",
+                "account_page": "https://scan.initia.xyz/yominet-1/accounts/${accountAddress}"
+            }
+        ],
+        "metadata": {"minitia": {"type": "minievm", "version": "v1.0.0"}},
+        "logo_URIs": {"png": "https://registry.initia.xyz/images/yominet.png"},
+        "slip44": 60,
+        "bech32_prefix": "init",
+        "network_type": "mainnet",
+        "evm_chain_id": 428962654539583
+    }"#;
+
+    const ASSETLIST: &str = r#"{
+        "$schema": "../../assetlist.schema.json",
+        "chain_name": "yominet",
+        "assets": [
+            {
+                "description": "The native token of Initia",
+                "denom_units": [
+                    {"denom": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06", "exponent": 0},
+                    {"denom": "INIT", "exponent": 18}
+                ],
+                "base": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06",
+                "display": "INIT",
+                "name": "Initia Native Token",
+                "symbol": "INIT",
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_add_ethereum_chain_parameter() {
+        let chain: Chain = serde_json::from_str(CHAIN).unwrap();
+        let assetlist: AssetList = serde_json::from_str(ASSETLIST).unwrap();
+
+        let params = AddEthereumChainParameter::new(&chain, &assetlist)
+            .expect("Failed to build chain parameters");
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "chainId": "0x18623a6a54f3f",
+                "chainName": "Yominet",
+                "nativeCurrency": {"name": "Initia Native Token", "symbol": "INIT", "decimals": 18},
+                "rpcUrls": ["https://jsonrpc.yominet.xyz"],
+                "blockExplorerUrls": ["https://scan.initia.xyz/yominet-1"],
+                "iconUrls": ["https://registry.initia.xyz/images/yominet.png"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_add_ethereum_chain_parameter_errors() {
+        let assetlist: AssetList = serde_json::from_str(ASSETLIST).unwrap();
+        let not_evm = |chain: &mut Chain| chain.evm_chain_id = None;
+        let no_rpc = |chain: &mut Chain| chain.apis.json_rpc.truncate(1);
+        let no_fee = |chain: &mut Chain| chain.fees.fee_tokens.truncate(1);
+
+        for (edit, expected) in [
+            (
+                not_evm as fn(&mut Chain),
+                Eip3085Error::NotEvm {
+                    chain_name: "yominet".to_string(),
+                },
+            ),
+            (
+                no_rpc,
+                Eip3085Error::MissingJsonRpc {
+                    chain_name: "yominet".to_string(),
+                },
+            ),
+            (
+                no_fee,
+                Eip3085Error::MissingFeeAsset {
+                    chain_name: "yominet".to_string(),
+                },
+            ),
+        ] {
+            let mut chain: Chain = serde_json::from_str(CHAIN).unwrap();
+            edit(&mut chain);
+            assert_eq!(
+                AddEthereumChainParameter::new(&chain, &assetlist),
+                Err(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry_add_ethereum_chain_parameter() {
+        let chains: ChainList = serde_json::from_str(&format!("[{CHAIN}]")).unwrap();
+        let assetlist: AssetList = serde_json::from_str(ASSETLIST).unwrap();
+
+        let registry = Registry::new(chains.clone(), vec![assetlist], ProfileList(vec![]));
+        assert!(registry.add_ethereum_chain_parameter("yominet").is_ok());
+        assert_eq!(
+            registry.add_ethereum_chain_parameter("initia"),
+            Err(Eip3085Error::UnknownChain {
+                chain_name: "initia".to_string()
+            })
+        );
+
+        let registry = Registry::new(chains, vec![], ProfileList(vec![]));
+        assert_eq!(
+            registry.add_ethereum_chain_parameter("yominet"),
+            Err(Eip3085Error::MissingAssetlist {
+                chain_name: "yominet".to_string()
+            })
+        );
+    }
+}
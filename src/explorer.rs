@@ -0,0 +1,338 @@
+use std::fmt;
+
+use crate::chain::{Chain, Explorer};
+
+pub const TX_HASH: &str = "txHash";
+pub const ACCOUNT_ADDRESS: &str = "accountAddress";
+pub const BLOCK_HEIGHT: &str = "blockHeight";
+pub const VALIDATOR_ADDRESS: &str = "validatorAddress";
+pub const PROPOSAL_ID: &str = "proposalId";
+pub const EVM_TX_HASH: &str = "evmTxHash";
+
+/// An explorer page and the value for its placeholder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Page<'a> {
+    Tx(&'a str),
+    Account(&'a str),
+    Block(u64),
+    Validator(&'a str),
+    Proposal(u64),
+    EvmTx(&'a str),
+}
+
+impl Page<'_> {
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            Page::Tx(_) => TX_HASH,
+            Page::Account(_) => ACCOUNT_ADDRESS,
+            Page::Block(_) => BLOCK_HEIGHT,
+            Page::Validator(_) => VALIDATOR_ADDRESS,
+            Page::Proposal(_) => PROPOSAL_ID,
+            Page::EvmTx(_) => EVM_TX_HASH,
+        }
+    }
+
+    fn template<'e>(&self, explorer: &'e Explorer) -> Option<&'e str> {
+        match self {
+            Page::Tx(_) => Some(&explorer.tx_page),
+            Page::Account(_) => Some(&explorer.account_page),
+            Page::Block(_) => explorer.block_page.as_deref(),
+            Page::Validator(_) => explorer.validator_page.as_deref(),
+            Page::Proposal(_) => explorer.proposal_page.as_deref(),
+            Page::EvmTx(_) => explorer.evm_tx_page.as_deref(),
+        }
+    }
+
+    fn value(&self) -> Result<String, LinkError> {
+        let invalid = |value: &str| LinkError::InvalidValue {
+            placeholder: self.placeholder(),
+            value: value.to_string(),
+        };
+        match *self {
+            Page::Tx(hash) => {
+                if hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(invalid(hash));
+                }
+                Ok(hash.to_string())
+            }
+            Page::EvmTx(hash) => {
+                let digits = hash.strip_prefix("0x").ok_or_else(|| invalid(hash))?;
+                if digits.len() != 64 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(invalid(hash));
+                }
+                Ok(hash.to_string())
+            }
+            Page::Account(address) | Page::Validator(address) => {
+                if address.is_empty() || !address.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                    return Err(invalid(address));
+                }
+                Ok(address.to_string())
+            }
+            Page::Block(n) | Page::Proposal(n) => Ok(n.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinkError {
+    NoExplorer {
+        chain_name: String,
+    },
+    UnsupportedPage {
+        kind: String,
+        placeholder: &'static str,
+    },
+    UnknownPlaceholder {
+        kind: String,
+        placeholder: String,
+    },
+    MissingPlaceholder {
+        kind: String,
+        placeholder: &'static str,
+    },
+    InvalidValue {
+        placeholder: &'static str,
+        value: String,
+    },
+}
+
+impl fmt::Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkError::NoExplorer { chain_name } => {
+                write!(f, "chain `{chain_name}` lists no matching explorer")
+            }
+            LinkError::UnsupportedPage { kind, placeholder } => {
+                write!(f, "explorer `{kind}` has no page for `${{{placeholder}}}`")
+            }
+            LinkError::UnknownPlaceholder { kind, placeholder } => {
+                write!(
+                    f,
+                    "explorer `{kind}` uses unknown placeholder `${{{placeholder}}}`"
+                )
+            }
+            LinkError::MissingPlaceholder { kind, placeholder } => {
+                write!(
+                    f,
+                    "explorer `{kind}` page lacks the `${{{placeholder}}}` placeholder"
+                )
+            }
+            LinkError::InvalidValue { placeholder, value } => {
+                write!(f, "`{value}` is not a valid `{placeholder}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LinkError {}
+
+impl Explorer {
+    pub fn supports(&self, page: &Page) -> bool {
+        page.template(self).is_some()
+    }
+
+    /// Fills the page template, percent-encoding the value. Fails if the template has any
+    /// placeholder other than the page's own or is missing it.
+    pub fn link(&self, page: &Page) -> Result<String, LinkError> {
+        let template = page
+            .template(self)
+            .ok_or_else(|| LinkError::UnsupportedPage {
+                kind: self.kind.clone(),
+                placeholder: page.placeholder(),
+            })?;
+        let value = percent_encode(&page.value()?);
+
+        let mut out = String::with_capacity(template.len() + value.len());
+        let mut found = false;
+        let mut rest = template;
+        while let Some(start) = rest.find("${") {
+            out.push_str(&rest[..start]);
+            let end = rest[start..].find('}').map(|i| start + i).ok_or_else(|| {
+                LinkError::UnknownPlaceholder {
+                    kind: self.kind.clone(),
+                    placeholder: rest[start + 2..].to_string(),
+                }
+            })?;
+            let name = &rest[start + 2..end];
+            if name != page.placeholder() {
+                return Err(LinkError::UnknownPlaceholder {
+                    kind: self.kind.clone(),
+                    placeholder: name.to_string(),
+                });
+            }
+            out.push_str(&value);
+            found = true;
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+
+        if !found {
+            return Err(LinkError::MissingPlaceholder {
+                kind: self.kind.clone(),
+                placeholder: page.placeholder(),
+            });
+        }
+        Ok(out)
+    }
+}
+
+impl Chain {
+    pub fn explorer_by_kind(&self, kind: &str) -> Option<&Explorer> {
+        self.explorers.iter().find(|e| e.kind == kind)
+    }
+
+    /// Explorers ordered by `preference` kinds first, then the rest in registry order.
+    pub fn explorers_by_preference<'a>(
+        &'a self,
+        preference: &'a [&str],
+    ) -> impl Iterator<Item = &'a Explorer> {
+        preference
+            .iter()
+            .filter_map(|kind| self.explorer_by_kind(kind))
+            .chain(
+                self.explorers
+                    .iter()
+                    .filter(|e| !preference.contains(&e.kind.as_str())),
+            )
+    }
+
+    /// Links `page` on the most preferred explorer that declares it.
+    pub fn explorer_link(&self, preference: &[&str], page: &Page) -> Result<String, LinkError> {
+        self.explorers_by_preference(preference)
+            .find(|e| e.supports(page))
+            .ok_or_else(|| LinkError::NoExplorer {
+                chain_name: self.chain_name.clone(),
+            })?
+            .link(page)
+    }
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for b in value.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{b:02X}"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TX: &str = "6A1FE1E7F0AB44B7C3D5E1B8B3A5F9B1C2D3E4F5A6B7C8D9E0F1A2B3C4D5E6F7";
+
+    fn chain() -> Chain {
+        serde_json::from_str(
+            r#"{
+                "chain_id": "yominet-1",
+                "chain_name": "yominet",
+                "pretty_name": "Yominet",
+                "fees": {"fee_tokens": []},
+                "apis": {},
+                "explorers": [
+                    {
+                        "kind": "initia scan",
+                        "url": "https://scan.initia.xyz/yominet-1",
+                        "tx_page": "https://scan.initia.xyz/yominet-1/txs/${txHash}",
+                        "account_page": "https://scan.initia.xyz/yominet-1/accounts/${accountAddress}",
+                        "block_page": "https://scan.initia.xyz/yominet-1/blocks/${blockHeight}"
+                    },
+                    {
+                        "kind": "blockscout",
+                        "url": "https://explorer.yominet.xyz",
+                        "tx_page": "https://explorer.yominet.xyz/tx/${txHash}",
+                        "account_page": "https://explorer.yominet.xyz/address/${accountAddress}",
+                        "evm_tx_page": "https://explorer.yominet.xyz/tx/${evmTxHash}",
+                        "proposal_page": "https://explorer.yominet.xyz/proposals/${proposalId}?chain=${chainId}"
+                    }
+                ],
+                "metadata": {},
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/yominet.png"},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"
+            }"#,
+        )
+        .expect("Failed to deserialize chain")
+    }
+
+    #[test]
+    fn test_explorer_links() {
+        let chain = chain();
+
+        assert_eq!(
+            chain.explorer_link(&[], &Page::Tx(TX)).unwrap(),
+            format!("https://scan.initia.xyz/yominet-1/txs/{TX}")
+        );
+        assert_eq!(
+            chain
+                .explorer_link(&["blockscout"], &Page::Account("init1abc"))
+                .unwrap(),
+            "https://explorer.yominet.xyz/address/init1abc"
+        );
+        assert_eq!(
+            chain
+                .explorer_link(&["blockscout"], &Page::Block(42))
+                .unwrap(),
+            "https://scan.initia.xyz/yominet-1/blocks/42"
+        );
+        let evm_tx = format!("0x{}", TX.to_lowercase());
+        assert_eq!(
+            chain.explorer_link(&[], &Page::EvmTx(&evm_tx)).unwrap(),
+            format!("https://explorer.yominet.xyz/tx/{evm_tx}")
+        );
+    }
+
+    #[test]
+    fn test_explorer_link_errors() {
+        let chain = chain();
+
+        assert_eq!(
+            chain.explorer_link(&[], &Page::Validator("initvaloper1abc")),
+            Err(LinkError::NoExplorer {
+                chain_name: "yominet".to_string()
+            })
+        );
+        assert_eq!(
+            chain.explorers[0].link(&Page::Proposal(1)),
+            Err(LinkError::UnsupportedPage {
+                kind: "initia scan".to_string(),
+                placeholder: PROPOSAL_ID
+            })
+        );
+        assert_eq!(
+            chain.explorer_link(&[], &Page::Proposal(1)),
+            Err(LinkError::UnknownPlaceholder {
+                kind: "blockscout".to_string(),
+                placeholder: "chainId".to_string()
+            })
+        );
+        assert!(matches!(
+            chain.explorer_link(&[], &Page::Tx("not-a-hash")),
+            Err(LinkError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            chain.explorer_link(&[], &Page::Account("init1abc/../x")),
+            Err(LinkError::InvalidValue { .. })
+        ));
+
+        let mut explorer = chain.explorers[0].clone();
+        explorer.tx_page = "https://scan.initia.xyz/txs".to_string();
+        assert_eq!(
+            explorer.link(&Page::Tx(TX)),
+            Err(LinkError::MissingPlaceholder {
+                kind: "initia scan".to_string(),
+                placeholder: TX_HASH
+            })
+        );
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("a b/c~"), "a%20b%2Fc~");
+    }
+}
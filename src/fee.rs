@@ -0,0 +1,183 @@
+use crate::{
+    amount::{Coin, Decimal, pow10},
+    chain::{FeeToken, Fees},
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GasTier {
+    FixedMin,
+    Low,
+    #[default]
+    Average,
+    High,
+}
+
+impl GasTier {
+    /// The tiers to try, in order, when this tier's gas price is missing. Higher tiers come
+    /// before lower ones, so a fallback only underpays the requested tier when nothing at or
+    /// above it is declared.
+    fn fallbacks(self) -> [GasTier; 4] {
+        use GasTier::*;
+        match self {
+            FixedMin => [FixedMin, Low, Average, High],
+            Low => [Low, Average, High, FixedMin],
+            Average => [Average, High, Low, FixedMin],
+            High => [High, Average, Low, FixedMin],
+        }
+    }
+}
+
+impl FeeToken {
+    pub fn tier_gas_price(&self, tier: GasTier) -> Option<f64> {
+        match tier {
+            GasTier::FixedMin => self.fixed_min_gas_price,
+            GasTier::Low => self.low_gas_price,
+            GasTier::Average => self.average_gas_price,
+            GasTier::High => self.high_gas_price,
+        }
+    }
+
+    /// The gas price for `tier`, falling back to the nearest declared tier.
+    pub fn gas_price(&self, tier: GasTier) -> Option<f64> {
+        tier.fallbacks()
+            .into_iter()
+            .find_map(|t| self.tier_gas_price(t))
+    }
+
+    /// `ceil(gas_price * gas_limit)` of this token, or `None` when the token declares no usable
+    /// gas price or the fee does not fit in a `u128`.
+    pub fn fee(&self, gas_limit: u64, tier: GasTier) -> Option<Coin> {
+        // Go through the shortest decimal form of the price so that e.g. 0.015 is exact.
+        let price: Decimal = self.gas_price(tier)?.to_string().parse().ok()?;
+        let product = price.atomics().checked_mul(gas_limit.into())?;
+        let unit = pow10(price.scale())?;
+        Some(Coin::new(product.div_ceil(unit), self.denom.clone()))
+    }
+}
+
+impl Fees {
+    /// The fee in every token that declares a gas price, in registry order.
+    pub fn estimate(&self, gas_limit: u64, tier: GasTier) -> Vec<Coin> {
+        self.fee_tokens
+            .iter()
+            .filter_map(|t| t.fee(gas_limit, tier))
+            .collect()
+    }
+
+    /// The first fee, in registry order, that `balances` can cover.
+    pub fn best_fee(&self, gas_limit: u64, tier: GasTier, balances: &[Coin]) -> Option<Coin> {
+        self.estimate(gas_limit, tier).into_iter().find(|fee| {
+            balances
+                .iter()
+                .any(|b| b.denom == fee.denom && b.amount >= fee.amount)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fees() -> Fees {
+        serde_json::from_str(
+            r#"{
+                "fee_tokens": [
+                    {
+                        "denom": "uinit",
+                        "fixed_min_gas_price": 0.015,
+                        "low_gas_price": 0.015,
+                        "average_gas_price": 0.025,
+                        "high_gas_price": 0.04
+                    },
+                    {
+                        "denom": "uusdc",
+                        "fixed_min_gas_price": 0.003
+                    },
+                    {
+                        "denom": "uether"
+                    }
+                ]
+            }"#,
+        )
+        .expect("Failed to deserialize fees")
+    }
+
+    #[test]
+    fn test_fee_tiers() {
+        let fees = fees();
+        let init = &fees.fee_tokens[0];
+
+        assert_eq!(
+            init.fee(200_000, GasTier::Average),
+            Some(Coin::new(5_000, "uinit"))
+        );
+        assert_eq!(
+            init.fee(200_000, GasTier::High),
+            Some(Coin::new(8_000, "uinit"))
+        );
+        assert_eq!(init.fee(1, GasTier::Low), Some(Coin::new(1, "uinit")));
+        assert_eq!(
+            init.fee(100_001, GasTier::FixedMin),
+            Some(Coin::new(1_501, "uinit"))
+        );
+
+        let usdc = &fees.fee_tokens[1];
+        assert_eq!(usdc.gas_price(GasTier::High), Some(0.003));
+        assert_eq!(
+            usdc.fee(1_000_000, GasTier::Average),
+            Some(Coin::new(3_000, "uusdc"))
+        );
+        assert_eq!(fees.fee_tokens[2].fee(1_000_000, GasTier::Average), None);
+    }
+
+    #[test]
+    fn test_fee_fallback_order() {
+        let token = FeeToken {
+            denom: "uinit".to_string(),
+            fixed_min_gas_price: Some(0.01),
+            low_gas_price: None,
+            average_gas_price: None,
+            high_gas_price: Some(0.05),
+        };
+
+        assert_eq!(token.gas_price(GasTier::Low), Some(0.05));
+        assert_eq!(token.gas_price(GasTier::Average), Some(0.05));
+
+        let token = FeeToken {
+            denom: "uinit".to_string(),
+            fixed_min_gas_price: Some(0.01),
+            low_gas_price: None,
+            average_gas_price: Some(0.025),
+            high_gas_price: None,
+        };
+        assert_eq!(token.gas_price(GasTier::FixedMin), Some(0.01));
+        assert_eq!(token.gas_price(GasTier::Low), Some(0.025));
+        assert_eq!(token.gas_price(GasTier::High), Some(0.025));
+    }
+
+    #[test]
+    fn test_estimate_and_best_fee() {
+        let fees = fees();
+
+        assert_eq!(
+            fees.estimate(100_000, GasTier::Average),
+            vec![Coin::new(2_500, "uinit"), Coin::new(300, "uusdc")]
+        );
+        assert_eq!(
+            fees.best_fee(
+                100_000,
+                GasTier::Average,
+                &[Coin::new(2_499, "uinit"), Coin::new(1_000, "uusdc")]
+            ),
+            Some(Coin::new(300, "uusdc"))
+        );
+        assert_eq!(
+            fees.best_fee(100_000, GasTier::Average, &[Coin::new(2_500, "uinit")]),
+            Some(Coin::new(2_500, "uinit"))
+        );
+        assert_eq!(
+            fees.best_fee(100_000, GasTier::Average, &[Coin::new(10, "uether")]),
+            None
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use crate::{
+    amount::{AmountError, Coin, Decimal, Rounding},
+    asset::{Asset, AssetList},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Maximum number of fractional digits; `None` keeps the full precision of the display
+    /// unit, or two digits in compact notation.
+    pub max_decimals: Option<u32>,
+    pub rounding: Rounding,
+    pub trim_zeros: bool,
+    pub group_separator: Option<char>,
+    /// Abbreviates large amounts with `K`, `M`, `B` and `T` suffixes.
+    pub compact: bool,
+    pub show_symbol: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            max_decimals: None,
+            rounding: Rounding::HalfUp,
+            trim_zeros: true,
+            group_separator: Some(','),
+            compact: false,
+            show_symbol: true,
+        }
+    }
+}
+
+const COMPACT_SUFFIXES: [(u32, &str); 5] = [(0, ""), (3, "K"), (6, "M"), (9, "B"), (12, "T")];
+
+impl Asset {
+    /// Renders a base-unit amount in the display unit, e.g. `1234500000` uinit as
+    /// `1,234.5 INIT`.
+    pub fn format_amount(
+        &self,
+        base_amount: u128,
+        options: &FormatOptions,
+    ) -> Result<String, AmountError> {
+        let display = self.to_display(base_amount)?;
+        let decimals = options
+            .max_decimals
+            .or(if options.compact { Some(2) } else { None });
+        let round = |value: Decimal| -> Result<Decimal, AmountError> {
+            let Some(decimals) = decimals else {
+                return Ok(value);
+            };
+            let value = value.round(decimals, options.rounding)?;
+            if !options.trim_zeros && value.scale() < decimals {
+                return value.rescale(decimals);
+            }
+            Ok(value)
+        };
+
+        let mut value = round(display)?;
+        let mut suffix = "";
+        if options.compact {
+            // Use the smallest suffix that keeps the rounded mantissa below 1000, so that
+            // 999,999.999 renders as `1M` rather than `1,000K`.
+            for (shift, s) in COMPACT_SUFFIXES {
+                value = round(Decimal::new(display.atomics(), display.scale() + shift)?)?;
+                suffix = s;
+                if value.integer_part() < 1000 {
+                    break;
+                }
+            }
+        }
+
+        let mut out = group_digits(value.integer_part(), options.group_separator);
+        let fraction = value.fractional_digits();
+        let fraction = if options.trim_zeros {
+            fraction.trim_end_matches('0')
+        } else {
+            &fraction
+        };
+        if !fraction.is_empty() {
+            out.push('.');
+            out.push_str(fraction);
+        }
+        out.push_str(suffix);
+        if options.show_symbol {
+            out.push(' ');
+            out.push_str(&self.symbol);
+        }
+        Ok(out)
+    }
+}
+
+impl AssetList {
+    /// Resolves a user-supplied denom to an asset and one of its unit denoms. Exact unit
+    /// denoms win over symbols, and exact matches win over case-insensitive ones.
+    pub fn resolve_unit(&self, denom: &str) -> Result<(&Asset, &str), AmountError> {
+        let by_unit = |eq: &dyn Fn(&str) -> bool| -> Vec<(&Asset, &str)> {
+            self.assets
+                .iter()
+                .filter_map(|a| {
+                    a.denom_units
+                        .iter()
+                        .find(|u| eq(&u.denom))
+                        .map(|u| (a, u.denom.as_str()))
+                })
+                .collect()
+        };
+        let by_symbol = |eq: &dyn Fn(&str) -> bool| -> Vec<(&Asset, &str)> {
+            self.assets
+                .iter()
+                .filter(|a| eq(&a.symbol))
+                .map(|a| (a, a.display.as_str()))
+                .collect()
+        };
+        let exact = |s: &str| s == denom;
+        let folded = |s: &str| s.eq_ignore_ascii_case(denom);
+
+        for candidates in [
+            by_unit(&exact),
+            by_symbol(&exact),
+            by_unit(&folded),
+            by_symbol(&folded),
+        ] {
+            match candidates.as_slice() {
+                [] => continue,
+                [single] => return Ok(*single),
+                _ => {
+                    return Err(AmountError::Ambiguous {
+                        denom: denom.to_string(),
+                    });
+                }
+            }
+        }
+        Err(AmountError::UnknownUnit {
+            denom: denom.to_string(),
+        })
+    }
+
+    /// Parses user input such as `1.5 INIT`, `1,234 USDC` or `1500000uinit` into a coin of
+    /// the asset's base denom.
+    pub fn parse_coin(&self, input: &str) -> Result<Coin, AmountError> {
+        let invalid = || AmountError::Invalid {
+            input: input.to_string(),
+        };
+        let input = input.trim();
+        let split = input
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .ok_or_else(invalid)?;
+        let (amount, denom) = input.split_at(split);
+        let denom = denom.trim_start();
+        if amount.is_empty() || denom.is_empty() {
+            return Err(invalid());
+        }
+        let amount: Decimal = strip_thousands(amount)
+            .ok_or_else(invalid)?
+            .parse()
+            .map_err(|_| invalid())?;
+
+        let (asset, unit) = self.resolve_unit(denom)?;
+        Ok(Coin::new(
+            asset.from_unit(amount, unit)?,
+            asset.base.clone(),
+        ))
+    }
+}
+
+/// Removes `,` thousands separators from an amount, or returns `None` when a comma is not one:
+/// every group after the first must be exactly three digits, and none may follow the `.`.
+fn strip_thousands(amount: &str) -> Option<String> {
+    let (int, frac) = match amount.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (amount, None),
+    };
+    if frac.is_some_and(|f| f.contains(',')) {
+        return None;
+    }
+    let mut groups = int.split(',');
+    let first = groups.next()?;
+    let mut out = first.to_string();
+    for group in groups {
+        if first.is_empty() || first.len() > 3 || group.len() != 3 {
+            return None;
+        }
+        out.push_str(group);
+    }
+    if let Some(frac) = frac {
+        out.push('.');
+        out.push_str(frac);
+    }
+    Some(out)
+}
+
+fn group_digits(value: u128, separator: Option<char>) -> String {
+    let digits = value.to_string();
+    let Some(separator) = separator else {
+        return digits;
+    };
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assetlist() -> AssetList {
+        serde_json::from_str(
+            r#"{
+                "$schema": "../../assetlist.schema.json",
+                "chain_name": "initia",
+                "assets": [
+                    {
+                        "description": "The native token of Initia",
+                        "denom_units": [
+                            {"denom": "uinit", "exponent": 0},
+                            {"denom": "INIT", "exponent": 6}
+                        ],
+                        "base": "uinit",
+                        "display": "INIT",
+                        "name": "Initia Native Token",
+                        "symbol": "INIT",
+                        "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+                    },
+                    {
+                        "description": "USDC on Initia",
+                        "denom_units": [
+                            {"denom": "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4", "exponent": 0},
+                            {"denom": "usdc", "exponent": 6}
+                        ],
+                        "base": "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4",
+                        "display": "usdc",
+                        "name": "USD Coin",
+                        "symbol": "USDC",
+                        "logo_URIs": {"png": "https://registry.initia.xyz/images/USDC.png"}
+                    }
+                ]
+            }"#,
+        )
+        .expect("Failed to deserialize asset list")
+    }
+
+    #[test]
+    fn test_format_default() {
+        let list = assetlist();
+        let init = &list.assets[0];
+        let options = FormatOptions::default();
+
+        assert_eq!(
+            init.format_amount(1_234_500_000, &options).unwrap(),
+            "1,234.5 INIT"
+        );
+        assert_eq!(init.format_amount(1, &options).unwrap(), "0.000001 INIT");
+        assert_eq!(init.format_amount(0, &options).unwrap(), "0 INIT");
+        assert_eq!(
+            list.assets[1].format_amount(2_000_000, &options).unwrap(),
+            "2 USDC"
+        );
+    }
+
+    #[test]
+    fn test_format_options() {
+        let list = assetlist();
+        let init = &list.assets[0];
+
+        let fixed = FormatOptions {
+            max_decimals: Some(2),
+            trim_zeros: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            init.format_amount(1_234_567_890, &fixed).unwrap(),
+            "1,234.57 INIT"
+        );
+        assert_eq!(init.format_amount(1_000_000, &fixed).unwrap(), "1.00 INIT");
+
+        let down = FormatOptions {
+            max_decimals: Some(2),
+            rounding: Rounding::Down,
+            group_separator: None,
+            show_symbol: false,
+            ..Default::default()
+        };
+        assert_eq!(init.format_amount(1_234_567_890, &down).unwrap(), "1234.56");
+
+        let untrimmed = FormatOptions {
+            trim_zeros: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            init.format_amount(1_500_000, &untrimmed).unwrap(),
+            "1.500000 INIT"
+        );
+    }
+
+    #[test]
+    fn test_format_compact() {
+        let list = assetlist();
+        let init = &list.assets[0];
+        let compact = FormatOptions {
+            compact: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            init.format_amount(999_000_000, &compact).unwrap(),
+            "999 INIT"
+        );
+        assert_eq!(
+            init.format_amount(1_234_500_000, &compact).unwrap(),
+            "1.23K INIT"
+        );
+        assert_eq!(
+            init.format_amount(12_345_678_000_000, &compact).unwrap(),
+            "12.35M INIT"
+        );
+        assert_eq!(
+            init.format_amount(3_000_000_000_000_000_000, &compact)
+                .unwrap(),
+            "3T INIT"
+        );
+    }
+
+    #[test]
+    fn test_parse_coin() {
+        let list = assetlist();
+        let usdc = "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4";
+
+        assert_eq!(
+            list.parse_coin("1.5 INIT").unwrap(),
+            Coin::new(1_500_000, "uinit")
+        );
+        assert_eq!(
+            list.parse_coin("1500000uinit").unwrap(),
+            Coin::new(1_500_000, "uinit")
+        );
+        assert_eq!(
+            list.parse_coin(" 1,234.5 init ").unwrap(),
+            Coin::new(1_234_500_000, "uinit")
+        );
+        assert_eq!(
+            list.parse_coin("1,234,567 INIT").unwrap(),
+            Coin::new(1_234_567_000_000, "uinit")
+        );
+        assert_eq!(
+            list.parse_coin("2 USDC").unwrap(),
+            Coin::new(2_000_000, usdc)
+        );
+        assert_eq!(
+            list.parse_coin(&format!("7{usdc}")).unwrap(),
+            Coin::new(7, usdc)
+        );
+    }
+
+    #[test]
+    fn test_parse_coin_errors() {
+        let list = assetlist();
+
+        assert!(matches!(
+            list.parse_coin("1.5"),
+            Err(AmountError::Invalid { .. })
+        ));
+        assert!(matches!(
+            list.parse_coin("INIT"),
+            Err(AmountError::Invalid { .. })
+        ));
+        assert!(matches!(
+            list.parse_coin("1..5 INIT"),
+            Err(AmountError::Invalid { .. })
+        ));
+        for input in [
+            "1,5 INIT",
+            "1,23 INIT",
+            ",5 INIT",
+            "1, INIT",
+            "1234,567 INIT",
+            "1.234,5 INIT",
+        ] {
+            assert!(
+                matches!(list.parse_coin(input), Err(AmountError::Invalid { .. })),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            list.parse_coin("1 ATOM"),
+            Err(AmountError::UnknownUnit {
+                denom: "ATOM".to_string()
+            })
+        );
+        assert!(matches!(
+            list.parse_coin("0.0000001 INIT"),
+            Err(AmountError::Precision { .. })
+        ));
+        assert!(matches!(
+            list.parse_coin("1.5uinit"),
+            Err(AmountError::Precision { .. })
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::chain::Chain;
+
+/// Coin type 60, used by Ethereum and by Initia chains.
+pub const ETH_COIN_TYPE: u32 = 60;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyAlgorithm {
+    /// Cosmos SDK keys, addressed by `RIPEMD160(SHA256(compressed_pubkey))`.
+    Secp256k1,
+    /// Ethermint-style keys, addressed by the last 20 bytes of
+    /// `Keccak256(uncompressed_pubkey[1..])`.
+    EthSecp256k1,
+}
+
+impl Chain {
+    /// The BIP-44 path `m/44'/{slip44}'/{account}'/0/{index}`.
+    pub fn derivation_path(&self, account: u32, index: u32) -> String {
+        format!("m/44'/{}'/{account}'/0/{index}", self.slip44)
+    }
+
+    /// Chains on coin type 60 use eth_secp256k1 keys; everything else uses plain secp256k1.
+    pub fn key_algorithm(&self) -> KeyAlgorithm {
+        if self.slip44 == ETH_COIN_TYPE {
+            KeyAlgorithm::EthSecp256k1
+        } else {
+            KeyAlgorithm::Secp256k1
+        }
+    }
+}
+
+#[cfg(feature = "hd")]
+pub use derive::*;
+
+#[cfg(feature = "hd")]
+mod derive {
+    use std::fmt;
+
+    use bip32::XPrv;
+    use bip39::Mnemonic;
+    use ripemd::Ripemd160;
+    use sha2::{Digest, Sha256};
+    use sha3::Keccak256;
+
+    use super::KeyAlgorithm;
+    use crate::{address::encode_bech32, chain::Chain};
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum HdError {
+        Mnemonic,
+        Derivation { path: String },
+    }
+
+    impl fmt::Display for HdError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                HdError::Mnemonic => f.write_str("invalid BIP-39 mnemonic"),
+                HdError::Derivation { path } => write!(f, "failed to derive `{path}`"),
+            }
+        }
+    }
+
+    impl std::error::Error for HdError {}
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct DerivedAccount {
+        pub path: String,
+        pub algorithm: KeyAlgorithm,
+        /// The 33-byte compressed public key.
+        pub public_key: Vec<u8>,
+        pub address_bytes: [u8; 20],
+        pub address: String,
+    }
+
+    impl Chain {
+        /// Derives the account at `m/44'/{slip44}'/{account}'/0/{index}` from an English
+        /// mnemonic with an empty passphrase.
+        pub fn derive_account(
+            &self,
+            mnemonic: &str,
+            account: u32,
+            index: u32,
+        ) -> Result<DerivedAccount, HdError> {
+            let mnemonic = Mnemonic::parse(mnemonic).map_err(|_| HdError::Mnemonic)?;
+            let path = self.derivation_path(account, index);
+            let derivation_error = || HdError::Derivation { path: path.clone() };
+            let xprv = XPrv::derive_from_path(
+                mnemonic.to_seed(""),
+                &path.parse().map_err(|_| derivation_error())?,
+            )
+            .map_err(|_| derivation_error())?;
+            let verifying_key = xprv.private_key().verifying_key();
+
+            let algorithm = self.key_algorithm();
+            let public_key = verifying_key.to_encoded_point(true).as_bytes().to_vec();
+            let digest: Vec<u8> = match algorithm {
+                KeyAlgorithm::Secp256k1 => Ripemd160::digest(Sha256::digest(&public_key)).to_vec(),
+                KeyAlgorithm::EthSecp256k1 => {
+                    let uncompressed = verifying_key.to_encoded_point(false);
+                    Keccak256::digest(&uncompressed.as_bytes()[1..])[12..].to_vec()
+                }
+            };
+            let address_bytes: [u8; 20] = digest.try_into().expect("address is 20 bytes");
+            let address = encode_bech32(&self.bech32_prefix, &address_bytes)
+                .map_err(|_| derivation_error())?;
+
+            Ok(DerivedAccount {
+                path,
+                algorithm,
+                public_key,
+                address_bytes,
+                address,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(slip44: u32, prefix: &str) -> Chain {
+        serde_json::from_str(&format!(
+            r#"{{
+                "chain_id": "test-1",
+                "chain_name": "test",
+                "pretty_name": "Test",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}},
+                "slip44": {slip44},
+                "bech32_prefix": "{prefix}",
+                "network_type": "mainnet"
+            }}"#
+        ))
+        .expect("Failed to deserialize chain")
+    }
+
+    #[test]
+    fn test_derivation_path() {
+        let initia = chain(60, "init");
+        assert_eq!(initia.derivation_path(0, 0), "m/44'/60'/0'/0/0");
+        assert_eq!(initia.key_algorithm(), KeyAlgorithm::EthSecp256k1);
+
+        let cosmoshub = chain(118, "cosmos");
+        assert_eq!(cosmoshub.derivation_path(1, 7), "m/44'/118'/1'/0/7");
+        assert_eq!(cosmoshub.key_algorithm(), KeyAlgorithm::Secp256k1);
+    }
+
+    #[cfg(feature = "hd")]
+    #[test]
+    fn test_derive_account() {
+        use crate::address::encode_hex;
+
+        const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let initia = chain(60, "init")
+            .derive_account(MNEMONIC, 0, 0)
+            .expect("Failed to derive initia account");
+        assert_eq!(
+            encode_hex(&initia.address_bytes),
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+        assert!(initia.address.starts_with("init1"));
+
+        let cosmoshub = chain(118, "cosmos").derive_account(MNEMONIC, 0, 0).unwrap();
+        assert_eq!(
+            cosmoshub.address,
+            "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"
+        );
+        assert_eq!(cosmoshub.public_key.len(), 33);
+
+        assert_eq!(
+            chain(60, "init").derive_account("abandon abandon", 0, 0),
+            Err(HdError::Mnemonic)
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::{chain::IbcChannel, registry::Registry};
+
+pub const TRANSFER_PORT: &str = "transfer";
+pub const NFT_TRANSFER_PORT: &str = "nft-transfer";
+pub const ICS20_VERSION: &str = "ics20-1";
+pub const ICS721_VERSION: &str = "ics721-1";
+
+/// A channel end listed by `chain_id`, pointing at `channel.chain_id`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChannelEdge<'a> {
+    pub chain_id: &'a str,
+    pub channel: &'a IbcChannel,
+}
+
+impl<'a> ChannelEdge<'a> {
+    pub fn counterparty_chain_id(&self) -> &'a str {
+        &self.channel.chain_id
+    }
+}
+
+/// Both ends of a channel, as listed by each chain on the same port.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChannelPair<'a> {
+    pub source: ChannelEdge<'a>,
+    pub destination: ChannelEdge<'a>,
+}
+
+/// Chains keyed by chain id, with an edge for every channel listed in their metadata.
+#[derive(Clone, Debug, Default)]
+pub struct IbcGraph<'a> {
+    edges: BTreeMap<&'a str, Vec<ChannelEdge<'a>>>,
+}
+
+impl<'a> IbcGraph<'a> {
+    pub fn new(registry: &'a Registry) -> Self {
+        Self::filtered(registry, |_| true)
+    }
+
+    /// Only keeps channels on `port_id`, and on `version` when given.
+    pub fn for_port(registry: &'a Registry, port_id: &str, version: Option<&str>) -> Self {
+        Self::filtered(registry, |c| {
+            c.port_id == port_id && version.is_none_or(|v| c.version == v)
+        })
+    }
+
+    pub fn transfer(registry: &'a Registry) -> Self {
+        Self::for_port(registry, TRANSFER_PORT, Some(ICS20_VERSION))
+    }
+
+    pub fn nft_transfer(registry: &'a Registry) -> Self {
+        Self::for_port(registry, NFT_TRANSFER_PORT, Some(ICS721_VERSION))
+    }
+
+    fn filtered(registry: &'a Registry, keep: impl Fn(&IbcChannel) -> bool) -> Self {
+        let mut edges: BTreeMap<&str, Vec<ChannelEdge>> = BTreeMap::new();
+        for chain in registry.chains() {
+            let chain_edges = edges.entry(chain.chain_id.as_str()).or_default();
+            chain_edges.extend(chain.metadata.ibc_channels.iter().filter(|c| keep(c)).map(
+                |channel| ChannelEdge {
+                    chain_id: &chain.chain_id,
+                    channel,
+                },
+            ));
+        }
+        Self { edges }
+    }
+
+    /// Registered chain ids, in order.
+    pub fn chains(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.edges.keys().copied()
+    }
+
+    pub fn channels(&self, chain_id: &str) -> &[ChannelEdge<'a>] {
+        self.edges.get(chain_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Counterparty chain ids of `chain_id`'s channels, in listing order and without
+    /// duplicates.
+    pub fn neighbors(&self, chain_id: &str) -> Vec<&'a str> {
+        let mut out: Vec<&str> = Vec::new();
+        for edge in self.channels(chain_id) {
+            if !out.contains(&edge.counterparty_chain_id()) {
+                out.push(edge.counterparty_chain_id());
+            }
+        }
+        out
+    }
+
+    /// The channel `from` lists towards `to` and the one `to` lists back on the same port,
+    /// preferring one with the same `version` too. The registry does not record which channels
+    /// are two ends of one connection, so when either side still has more than one candidate,
+    /// as after a client expiry leaves a second `transfer` channel, that channel is skipped
+    /// rather than paired with a guess; `None` if no channel pairs up unambiguously.
+    pub fn channel_pair(&self, from: &str, to: &str) -> Option<ChannelPair<'a>> {
+        self.channels(from)
+            .iter()
+            .filter(|e| e.counterparty_chain_id() == to)
+            .find_map(|source| {
+                let [destination] = self.partners(source, to)[..] else {
+                    return None;
+                };
+                let [back] = self.partners(&destination, from)[..] else {
+                    return None;
+                };
+                (back == *source).then_some(ChannelPair {
+                    source: *source,
+                    destination,
+                })
+            })
+    }
+
+    /// Channels `to` lists back towards `edge`'s chain on its port, narrowed to those on its
+    /// `version` when any are.
+    fn partners(&self, edge: &ChannelEdge<'a>, to: &str) -> Vec<ChannelEdge<'a>> {
+        let candidates: Vec<_> = self
+            .channels(to)
+            .iter()
+            .filter(|e| {
+                e.counterparty_chain_id() == edge.chain_id
+                    && e.channel.port_id == edge.channel.port_id
+            })
+            .copied()
+            .collect();
+        if candidates
+            .iter()
+            .any(|e| e.channel.version == edge.channel.version)
+        {
+            candidates
+                .into_iter()
+                .filter(|e| e.channel.version == edge.channel.version)
+                .collect()
+        } else {
+            candidates
+        }
+    }
+
+    /// The fewest channel hops from `from` to `to`, following channels as listed by the
+    /// sending chain. Returns an empty path when both are the same chain.
+    pub fn shortest_path(&self, from: &str, to: &str) -> Option<Vec<ChannelEdge<'a>>> {
+        if from == to {
+            return Some(vec![]);
+        }
+        let mut previous: HashMap<&str, ChannelEdge> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(chain_id) = queue.pop_front() {
+            for edge in self.channels(chain_id) {
+                let next = edge.counterparty_chain_id();
+                if next == from || previous.contains_key(next) {
+                    continue;
+                }
+                previous.insert(next, *edge);
+                if next == to {
+                    let mut path = vec![*edge];
+                    while let Some(edge) = previous.get(path[0].chain_id) {
+                        path.insert(0, *edge);
+                    }
+                    return Some(path);
+                }
+                queue.push_back(next);
+            }
+        }
+        None
+    }
+}
+
+impl Registry {
+    pub fn ibc_graph(&self) -> IbcGraph<'_> {
+        IbcGraph::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain::ChainList, profile::ProfileList};
+
+    fn chain(chain_id: &str, channels: &[(&str, &str, &str)]) -> String {
+        let channels: Vec<_> = channels
+            .iter()
+            .map(|(counterparty, port, channel)| {
+                let version = if *port == TRANSFER_PORT {
+                    ICS20_VERSION
+                } else {
+                    ICS721_VERSION
+                };
+                format!(
+                    r#"{{"chain_id": "{counterparty}", "port_id": "{port}", "channel_id": "{channel}", "version": "{version}"}}"#
+                )
+            })
+            .collect();
+        format!(
+            r#"{{
+                "chain_id": "{chain_id}",
+                "chain_name": "{chain_id}",
+                "pretty_name": "{chain_id}",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{"ibc_channels": [{}]}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"
+            }}"#,
+            channels.join(",")
+        )
+    }
+
+    fn registry() -> Registry {
+        let chains = [
+            chain(
+                "interwoven-1",
+                &[
+                    ("yominet-1", "transfer", "channel-25"),
+                    ("yominet-1", "nft-transfer", "channel-26"),
+                    ("echelon-1", "transfer", "channel-30"),
+                    ("osmosis-1", "transfer", "channel-71"),
+                ],
+            ),
+            chain(
+                "yominet-1",
+                &[
+                    ("interwoven-1", "transfer", "channel-0"),
+                    ("interwoven-1", "nft-transfer", "channel-1"),
+                ],
+            ),
+            chain("echelon-1", &[("interwoven-1", "transfer", "channel-0")]),
+        ];
+        let chains: ChainList = serde_json::from_str(&format!("[{}]", chains.join(",")))
+            .expect("Failed to deserialize chains");
+        Registry::new(chains, vec![], ProfileList(vec![]))
+    }
+
+    #[test]
+    fn test_neighbors_and_ports() {
+        let registry = registry();
+        let graph = registry.ibc_graph();
+
+        assert_eq!(
+            graph.chains().collect::<Vec<_>>(),
+            vec!["echelon-1", "interwoven-1", "yominet-1"]
+        );
+        assert_eq!(
+            graph.neighbors("interwoven-1"),
+            vec!["yominet-1", "echelon-1", "osmosis-1"]
+        );
+        assert_eq!(graph.channels("interwoven-1").len(), 4);
+        assert!(graph.channels("osmosis-1").is_empty());
+
+        let nft = IbcGraph::nft_transfer(&registry);
+        assert_eq!(nft.neighbors("interwoven-1"), vec!["yominet-1"]);
+        assert_eq!(nft.neighbors("echelon-1"), Vec::<&str>::new());
+        assert_eq!(IbcGraph::transfer(&registry).channels("yominet-1").len(), 1);
+    }
+
+    #[test]
+    fn test_channel_pair() {
+        let registry = registry();
+
+        let pair = IbcGraph::transfer(&registry)
+            .channel_pair("yominet-1", "interwoven-1")
+            .expect("Failed to find channel pair");
+        assert_eq!(pair.source.channel.channel_id, "channel-0");
+        assert_eq!(pair.destination.channel.channel_id, "channel-25");
+
+        let pair = IbcGraph::nft_transfer(&registry)
+            .channel_pair("interwoven-1", "yominet-1")
+            .unwrap();
+        assert_eq!(pair.source.channel.channel_id, "channel-26");
+        assert_eq!(pair.destination.channel.channel_id, "channel-1");
+
+        assert_eq!(
+            registry
+                .ibc_graph()
+                .channel_pair("interwoven-1", "osmosis-1"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_channel_pair_with_several_channels() {
+        let pair = |initia: &[(&str, &str, &str)], noble: String| {
+            let chains = format!("[{},{noble}]", chain("interwoven-1", initia));
+            let chains: ChainList =
+                serde_json::from_str(&chains).expect("Failed to deserialize chains");
+            let registry = Registry::new(chains, vec![], ProfileList(vec![]));
+            registry
+                .ibc_graph()
+                .channel_pair("interwoven-1", "noble-1")
+                .map(|p| {
+                    (
+                        p.source.channel.channel_id.clone(),
+                        p.destination.channel.channel_id.clone(),
+                    )
+                })
+        };
+        let noble = chain(
+            "noble-1",
+            &[
+                ("interwoven-1", "transfer", "channel-129"),
+                ("interwoven-1", "transfer", "channel-130"),
+            ],
+        );
+
+        assert_eq!(
+            pair(
+                &[
+                    ("noble-1", "transfer", "channel-3"),
+                    ("noble-1", "transfer", "channel-4"),
+                ],
+                noble.clone(),
+            ),
+            None
+        );
+        assert_eq!(
+            pair(&[("noble-1", "transfer", "channel-3")], noble.clone()),
+            None
+        );
+        assert_eq!(
+            pair(
+                &[("noble-1", "transfer", "channel-3")],
+                noble.replace(
+                    r#""channel_id": "channel-130", "version": "ics20-1""#,
+                    r#""channel_id": "channel-130", "version": "ics20-2""#,
+                ),
+            ),
+            Some(("channel-3".to_string(), "channel-129".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let registry = registry();
+        let graph = IbcGraph::transfer(&registry);
+
+        let path = graph
+            .shortest_path("yominet-1", "echelon-1")
+            .expect("Failed to find path");
+        let hops: Vec<_> = path
+            .iter()
+            .map(|e| (e.chain_id, e.channel.channel_id.as_str()))
+            .collect();
+        assert_eq!(
+            hops,
+            vec![("yominet-1", "channel-0"), ("interwoven-1", "channel-30")]
+        );
+
+        assert_eq!(graph.shortest_path("echelon-1", "echelon-1"), Some(vec![]));
+        assert_eq!(graph.shortest_path("osmosis-1", "echelon-1"), None);
+        assert_eq!(
+            IbcGraph::nft_transfer(&registry).shortest_path("yominet-1", "echelon-1"),
+            None
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{asset::Asset, registry::Registry};
+
+/// The origin chain and base denom of an asset, shared by every bridged copy of it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AssetId {
+    pub chain_name: String,
+    pub base_denom: String,
+}
+
+impl AssetId {
+    pub fn new(chain_name: impl Into<String>, base_denom: impl Into<String>) -> Self {
+        Self {
+            chain_name: chain_name.into(),
+            base_denom: base_denom.into(),
+        }
+    }
+}
+
+impl fmt::Display for AssetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.chain_name, self.base_denom)
+    }
+}
+
+impl Asset {
+    /// The origin as declared by this asset alone: traces are ordered from the origin
+    /// outwards, so the first counterparty is the origin. An asset without traces is its own
+    /// origin on `chain_name`. Prefer [`Registry::resolve_asset_id`], which also handles
+    /// assets that only list their last hop.
+    pub fn asset_id(&self, chain_name: &str) -> AssetId {
+        match self.traces.first() {
+            Some(trace) => AssetId::new(trace.chain_name(), trace.base_denom()),
+            None => AssetId::new(chain_name, &self.base),
+        }
+    }
+}
+
+impl Registry {
+    pub fn asset_id(&self, chain_name: &str, base: &str) -> Option<AssetId> {
+        self.asset(chain_name, base)
+            .map(|a| self.resolve_asset_id(chain_name, a))
+    }
+
+    /// Follows the last trace of each asset hop by hop, like [`Registry::provenance`], and
+    /// returns the asset without traces it ends at. When a hop leaves the registry or loops,
+    /// the furthest asset reached names the origin through its first trace.
+    pub fn resolve_asset_id(&self, chain_name: &str, asset: &Asset) -> AssetId {
+        let mut visited = HashSet::new();
+        let mut current = (chain_name, asset);
+        loop {
+            let (chain_name, asset) = current;
+            let Some(trace) = asset.traces.last() else {
+                return AssetId::new(chain_name, &asset.base);
+            };
+            match self.asset(trace.chain_name(), trace.base_denom()) {
+                Some(next) if visited.insert((chain_name, asset.base.as_str())) => {
+                    current = (trace.chain_name(), next);
+                }
+                _ => return asset.asset_id(chain_name),
+            }
+        }
+    }
+
+    /// Every asset across all assetlists that represents `id`, including the origin asset
+    /// itself when it is registered.
+    pub fn equivalent_assets(&self, id: &AssetId) -> Vec<(&str, &Asset)> {
+        self.assetlists()
+            .iter()
+            .flat_map(|list| {
+                list.assets
+                    .iter()
+                    .filter(|a| self.resolve_asset_id(&list.chain_name, a) == *id)
+                    .map(|a| (list.chain_name.as_str(), a))
+            })
+            .collect()
+    }
+
+    pub fn asset_groups(&self) -> BTreeMap<AssetId, Vec<(&str, &Asset)>> {
+        let mut groups: BTreeMap<AssetId, Vec<(&str, &Asset)>> = BTreeMap::new();
+        for list in self.assetlists() {
+            for asset in &list.assets {
+                groups
+                    .entry(self.resolve_asset_id(&list.chain_name, asset))
+                    .or_default()
+                    .push((list.chain_name.as_str(), asset));
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{asset::AssetList, chain::ChainList, profile::ProfileList};
+
+    const USDC: &str = "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4";
+
+    fn registry() -> Registry {
+        let initia: AssetList = serde_json::from_str(&format!(
+            r#"{{
+                "$schema": "../../assetlist.schema.json",
+                "chain_name": "initia",
+                "assets": [
+                    {{
+                        "description": "The native token of Initia",
+                        "denom_units": [{{"denom": "uinit", "exponent": 0}}],
+                        "base": "uinit",
+                        "display": "uinit",
+                        "name": "Initia Native Token",
+                        "symbol": "INIT",
+                        "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}}
+                    }},
+                    {{
+                        "description": "USDC on Initia",
+                        "denom_units": [{{"denom": "{USDC}", "exponent": 0}}],
+                        "base": "{USDC}",
+                        "display": "{USDC}",
+                        "name": "USD Coin",
+                        "symbol": "USDC",
+                        "traces": [
+                            {{
+                                "type": "ibc",
+                                "counterparty": {{"chain_name": "noble", "base_denom": "uusdc", "channel_id": "channel-129"}},
+                                "chain": {{"channel_id": "channel-3", "path": "transfer/channel-3/uusdc"}}
+                            }}
+                        ],
+                        "logo_URIs": {{"png": "https://registry.initia.xyz/images/USDC.png"}}
+                    }}
+                ]
+            }}"#
+        ))
+        .expect("Failed to deserialize assetlist");
+        let yominet: AssetList = serde_json::from_str(&format!(
+            r#"{{
+                "$schema": "../../assetlist.schema.json",
+                "chain_name": "yominet",
+                "assets": [
+                    {{
+                        "description": "USDC on Yominet",
+                        "denom_units": [{{"denom": "l2/usdc", "exponent": 0}}],
+                        "base": "l2/usdc",
+                        "display": "l2/usdc",
+                        "name": "USD Coin",
+                        "symbol": "USDC",
+                        "traces": [
+                            {{
+                                "type": "ibc",
+                                "counterparty": {{"chain_name": "noble", "base_denom": "uusdc", "channel_id": "channel-129"}},
+                                "chain": {{"channel_id": "channel-3", "path": "transfer/channel-3/uusdc"}}
+                            }},
+                            {{
+                                "type": "op",
+                                "counterparty": {{"base_denom": "{USDC}", "chain_name": "initia"}},
+                                "chain": {{"bridge_id": "11"}}
+                            }}
+                        ],
+                        "logo_URIs": {{"png": "https://registry.initia.xyz/images/USDC.png"}}
+                    }},
+                    {{
+                        "description": "USDC on Yominet, listing only its last hop",
+                        "denom_units": [{{"denom": "l2/usdc-op", "exponent": 0}}],
+                        "base": "l2/usdc-op",
+                        "display": "l2/usdc-op",
+                        "name": "USD Coin",
+                        "symbol": "USDC",
+                        "traces": [
+                            {{
+                                "type": "op",
+                                "counterparty": {{"base_denom": "{USDC}", "chain_name": "initia"}},
+                                "chain": {{"bridge_id": "11"}}
+                            }}
+                        ],
+                        "logo_URIs": {{"png": "https://registry.initia.xyz/images/USDC.png"}}
+                    }},
+                    {{
+                        "description": "INIT on Yominet",
+                        "denom_units": [{{"denom": "l2/init", "exponent": 0}}],
+                        "base": "l2/init",
+                        "display": "l2/init",
+                        "name": "Initia Native Token",
+                        "symbol": "INIT",
+                        "traces": [
+                            {{
+                                "type": "op",
+                                "counterparty": {{"base_denom": "uinit", "chain_name": "initia"}},
+                                "chain": {{"bridge_id": "11"}}
+                            }}
+                        ],
+                        "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}}
+                    }}
+                ]
+            }}"#
+        ))
+        .expect("Failed to deserialize assetlist");
+        Registry::new(
+            ChainList(vec![]),
+            vec![initia, yominet],
+            ProfileList(vec![]),
+        )
+    }
+
+    #[test]
+    fn test_asset_id() {
+        let registry = registry();
+
+        assert_eq!(
+            registry.asset_id("yominet", "l2/usdc"),
+            Some(AssetId::new("noble", "uusdc"))
+        );
+        assert_eq!(
+            registry.asset_id("initia", "uinit"),
+            Some(AssetId::new("initia", "uinit"))
+        );
+        assert_eq!(registry.asset_id("initia", "uusdc"), None);
+
+        let last_hop_only = registry.asset("yominet", "l2/usdc-op").unwrap();
+        assert_eq!(
+            last_hop_only.asset_id("yominet"),
+            AssetId::new("initia", USDC)
+        );
+        assert_eq!(
+            registry.asset_id("yominet", "l2/usdc-op"),
+            Some(AssetId::new("noble", "uusdc"))
+        );
+        assert_eq!(AssetId::new("noble", "uusdc").to_string(), "noble:uusdc");
+    }
+
+    #[test]
+    fn test_equivalent_assets() {
+        let registry = registry();
+
+        let usdc: Vec<_> = registry
+            .equivalent_assets(&AssetId::new("noble", "uusdc"))
+            .into_iter()
+            .map(|(chain, a)| (chain, a.base.as_str()))
+            .collect();
+        assert_eq!(
+            usdc,
+            vec![
+                ("initia", USDC),
+                ("yominet", "l2/usdc"),
+                ("yominet", "l2/usdc-op")
+            ]
+        );
+
+        let groups = registry.asset_groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&AssetId::new("initia", "uinit")].len(), 2);
+    }
+}
@@ -0,0 +1,406 @@
+use crate::{
+    chain::Chain,
+    registry::Registry,
+    validate::{Diagnostic, assetlist_file, chain_file, rules},
+};
+
+impl Registry {
+    /// Checks references between registry files: trace counterparties, IBC channel
+    /// counterparties, fee tokens, OPinit denoms and assetlist owners.
+    pub fn check_integrity(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+
+        for chain in self.chains() {
+            self.check_fee_tokens(chain, &mut out);
+            self.check_op_denoms(chain, &mut out);
+            self.check_ibc_channels(chain, &mut out);
+        }
+
+        for list in self.assetlists() {
+            let file = assetlist_file(&list.chain_name);
+            if self.chain_by_name(&list.chain_name).is_none() {
+                out.push(
+                    Diagnostic::error(
+                        rules::REGISTRY_UNKNOWN_ASSETLIST_CHAIN,
+                        "$.chain_name",
+                        format!("chain `{}` is not in the registry", list.chain_name),
+                    )
+                    .with_file(&file),
+                );
+            }
+            for (i, asset) in list.assets.iter().enumerate() {
+                for (j, trace) in asset.traces.iter().enumerate() {
+                    if self.chain_by_name(trace.chain_name()).is_none() {
+                        out.push(
+                            Diagnostic::error(
+                                rules::REGISTRY_UNKNOWN_TRACE_CHAIN,
+                                format!("$.assets[{i}].traces[{j}].counterparty.chain_name"),
+                                format!(
+                                    "trace counterparty chain `{}` is not in the registry",
+                                    trace.chain_name()
+                                ),
+                            )
+                            .with_file(&file),
+                        );
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    fn check_fee_tokens(&self, chain: &Chain, out: &mut Vec<Diagnostic>) {
+        if chain.fees.fee_tokens.is_empty() {
+            return;
+        }
+        if self.assetlist_for_chain(chain).is_none() {
+            out.push(
+                Diagnostic::error(
+                    rules::REGISTRY_MISSING_ASSETLIST,
+                    "$.fees.fee_tokens",
+                    format!(
+                        "chain `{}` declares fee tokens but has no assetlist",
+                        chain.chain_name
+                    ),
+                )
+                .with_file(chain_file(&chain.chain_name)),
+            );
+            return;
+        }
+        for (i, token) in chain.fees.fee_tokens.iter().enumerate() {
+            if self.asset(&chain.chain_name, &token.denom).is_none() {
+                out.push(
+                    Diagnostic::error(
+                        rules::REGISTRY_FEE_TOKEN_NOT_IN_ASSETLIST,
+                        format!("$.fees.fee_tokens[{i}].denom"),
+                        format!(
+                            "fee token `{}` is not an asset base in the `{}` assetlist",
+                            token.denom, chain.chain_name
+                        ),
+                    )
+                    .with_file(chain_file(&chain.chain_name)),
+                );
+            }
+        }
+    }
+
+    fn check_op_denoms(&self, chain: &Chain, out: &mut Vec<Diagnostic>) {
+        if chain.metadata.op_denoms.is_empty() {
+            return;
+        }
+        let Some(l1) = self.l1_for(chain) else {
+            out.push(
+                Diagnostic::warning(
+                    rules::REGISTRY_MISSING_L1,
+                    "$.metadata.op_denoms",
+                    format!(
+                        "chain `{}` declares op denoms but there is no `{}` L1 to check them against",
+                        chain.chain_name, chain.network_type
+                    ),
+                )
+                .with_file(chain_file(&chain.chain_name)),
+            );
+            return;
+        };
+        if self.assetlist_for_chain(l1).is_none() {
+            out.push(
+                Diagnostic::error(
+                    rules::REGISTRY_MISSING_ASSETLIST,
+                    "$.metadata.op_denoms",
+                    format!(
+                        "chain `{}` declares op denoms but its L1 `{}` has no assetlist",
+                        chain.chain_name, l1.chain_name
+                    ),
+                )
+                .with_file(chain_file(&chain.chain_name)),
+            );
+            return;
+        }
+        for (i, denom) in chain.metadata.op_denoms.iter().enumerate() {
+            if self.asset(&l1.chain_name, denom).is_none() {
+                out.push(
+                    Diagnostic::error(
+                        rules::REGISTRY_OP_DENOM_NOT_ON_L1,
+                        format!("$.metadata.op_denoms[{i}]"),
+                        format!(
+                            "op denom `{denom}` is not an asset base in the `{}` assetlist",
+                            l1.chain_name
+                        ),
+                    )
+                    .with_file(chain_file(&chain.chain_name)),
+                );
+            }
+        }
+    }
+
+    fn check_ibc_channels(&self, chain: &Chain, out: &mut Vec<Diagnostic>) {
+        for (i, channel) in chain.metadata.ibc_channels.iter().enumerate() {
+            let path = format!("$.metadata.ibc_channels[{i}]");
+            let Some(counterparty) = self.chain_by_id(&channel.chain_id) else {
+                out.push(
+                    Diagnostic::warning(
+                        rules::REGISTRY_UNKNOWN_IBC_CHAIN,
+                        format!("{path}.chain_id"),
+                        format!(
+                            "channel counterparty `{}` is not in the registry",
+                            channel.chain_id
+                        ),
+                    )
+                    .with_file(chain_file(&chain.chain_name)),
+                );
+                continue;
+            };
+            let has_reverse = counterparty
+                .metadata
+                .ibc_channels
+                .iter()
+                .any(|c| c.chain_id == chain.chain_id && c.port_id == channel.port_id);
+            if !has_reverse {
+                out.push(
+                    Diagnostic::error(
+                        rules::REGISTRY_MISSING_REVERSE_CHANNEL,
+                        path,
+                        format!(
+                            "`{}` lists no `{}` channel back to `{}`",
+                            counterparty.chain_id, channel.port_id, chain.chain_id
+                        ),
+                    )
+                    .with_file(chain_file(&chain.chain_name)),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        asset::AssetList,
+        chain::ChainList,
+        profile::ProfileList,
+        registry::Registry,
+        validate::{Validate, rules},
+    };
+
+    fn registry(chains: &str, assetlists: &[&str]) -> Registry {
+        let chains: ChainList = serde_json::from_str(chains).expect("Failed to deserialize chains");
+        let assetlists = assetlists
+            .iter()
+            .map(|json| serde_json::from_str::<AssetList>(json).expect("Failed to deserialize"))
+            .collect();
+        Registry::new(chains, assetlists, ProfileList(vec![]))
+    }
+
+    const CHAINS: &str = r#"[
+        {
+            "chain_id": "interwoven-1",
+            "chain_name": "initia",
+            "pretty_name": "Initia",
+            "fees": {"fee_tokens": [{"denom": "uinit"}, {"denom": "uusdc"}]},
+            "apis": {},
+            "explorers": [],
+            "metadata": {
+                "is_l1": true,
+                "ibc_channels": [
+                    {"chain_id": "yominet-1", "port_id": "transfer", "channel_id": "channel-25", "version": "ics20-1"},
+                    {"chain_id": "osmosis-1", "port_id": "transfer", "channel_id": "channel-71", "version": "ics20-1"}
+                ]
+            },
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"},
+            "slip44": 60,
+            "bech32_prefix": "init",
+            "network_type": "mainnet"
+        },
+        {
+            "chain_id": "yominet-1",
+            "chain_name": "yominet",
+            "pretty_name": "Yominet",
+            "fees": {"fee_tokens": []},
+            "apis": {},
+            "explorers": [],
+            "metadata": {
+                "op_bridge_id": "11",
+                "op_denoms": ["uinit", "uether"],
+                "ibc_channels": [
+                    {"chain_id": "interwoven-1", "port_id": "nft-transfer", "channel_id": "channel-1", "version": "ics721-1"}
+                ]
+            },
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/yominet.png"},
+            "slip44": 60,
+            "bech32_prefix": "init",
+            "network_type": "mainnet"
+        }
+    ]"#;
+
+    const INITIA_ASSETS: &str = r#"{
+        "$schema": "../../assetlist.schema.json",
+        "chain_name": "initia",
+        "assets": [
+            {
+                "description": "The native token of Initia",
+                "denom_units": [{"denom": "uinit", "exponent": 0}, {"denom": "INIT", "exponent": 6}],
+                "base": "uinit",
+                "display": "INIT",
+                "name": "Initia Native Token",
+                "symbol": "INIT",
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+            },
+            {
+                "description": "USDC on Initia",
+                "denom_units": [{"denom": "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4", "exponent": 0}, {"denom": "USDC", "exponent": 6}],
+                "base": "ibc/6490A7EAB61059BFC1CDDEB05917DD70BDF3A611654162A1A47DB930D40D8AF4",
+                "display": "USDC",
+                "name": "USD Coin",
+                "symbol": "USDC",
+                "traces": [
+                    {
+                        "type": "ibc",
+                        "counterparty": {"chain_name": "noble", "base_denom": "uusdc", "channel_id": "channel-129"},
+                        "chain": {"channel_id": "channel-3", "path": "transfer/channel-3/uusdc"}
+                    }
+                ],
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/USDC.png"}
+            }
+        ]
+    }"#;
+
+    const ORPHAN_ASSETS: &str = r#"{
+        "$schema": "../../assetlist.schema.json",
+        "chain_name": "orphan",
+        "assets": []
+    }"#;
+
+    #[test]
+    fn test_check_integrity() {
+        let registry = registry(CHAINS, &[INITIA_ASSETS, ORPHAN_ASSETS]);
+
+        let diagnostics = registry.check_integrity();
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.rule, d.file.as_deref().unwrap(), d.path.as_str()))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                (
+                    rules::REGISTRY_FEE_TOKEN_NOT_IN_ASSETLIST,
+                    "chains/initia/chain.json",
+                    "$.fees.fee_tokens[1].denom"
+                ),
+                (
+                    rules::REGISTRY_MISSING_REVERSE_CHANNEL,
+                    "chains/initia/chain.json",
+                    "$.metadata.ibc_channels[0]"
+                ),
+                (
+                    rules::REGISTRY_UNKNOWN_IBC_CHAIN,
+                    "chains/initia/chain.json",
+                    "$.metadata.ibc_channels[1].chain_id"
+                ),
+                (
+                    rules::REGISTRY_OP_DENOM_NOT_ON_L1,
+                    "chains/yominet/chain.json",
+                    "$.metadata.op_denoms[1]"
+                ),
+                (
+                    rules::REGISTRY_MISSING_REVERSE_CHANNEL,
+                    "chains/yominet/chain.json",
+                    "$.metadata.ibc_channels[0]"
+                ),
+                (
+                    rules::REGISTRY_UNKNOWN_TRACE_CHAIN,
+                    "chains/initia/assetlist.json",
+                    "$.assets[1].traces[0].counterparty.chain_name"
+                ),
+                (
+                    rules::REGISTRY_UNKNOWN_ASSETLIST_CHAIN,
+                    "chains/orphan/assetlist.json",
+                    "$.chain_name"
+                ),
+            ]
+        );
+        assert!(!diagnostics[2].is_error());
+    }
+
+    #[test]
+    fn test_check_fee_tokens_without_assetlist() {
+        let registry = registry(CHAINS, &[]);
+
+        let found: Vec<_> = registry
+            .check_integrity()
+            .into_iter()
+            .filter(|d| d.file.as_deref() == Some("chains/initia/chain.json"))
+            .map(|d| (d.rule, d.path))
+            .collect();
+        assert_eq!(
+            found[0],
+            (
+                rules::REGISTRY_MISSING_ASSETLIST,
+                "$.fees.fee_tokens".to_string()
+            )
+        );
+        assert!(
+            found
+                .iter()
+                .all(|(rule, _)| *rule != rules::REGISTRY_FEE_TOKEN_NOT_IN_ASSETLIST)
+        );
+    }
+
+    #[test]
+    fn test_check_op_denoms_without_l1_assetlist() {
+        let registry = registry(CHAINS, &[]);
+
+        let found: Vec<_> = registry
+            .check_integrity()
+            .into_iter()
+            .filter(|d| d.file.as_deref() == Some("chains/yominet/chain.json"))
+            .map(|d| (d.rule, d.path))
+            .collect();
+        assert_eq!(
+            found[0],
+            (
+                rules::REGISTRY_MISSING_ASSETLIST,
+                "$.metadata.op_denoms".to_string()
+            )
+        );
+        assert!(
+            found
+                .iter()
+                .all(|(rule, _)| *rule != rules::REGISTRY_OP_DENOM_NOT_ON_L1)
+        );
+    }
+
+    #[test]
+    fn test_check_op_denoms_without_l1() {
+        let registry = registry(
+            &CHAINS.replace(r#""is_l1": true"#, r#""is_l1": false"#),
+            &[],
+        );
+
+        let diagnostics: Vec<_> = registry
+            .check_integrity()
+            .into_iter()
+            .filter(|d| d.file.as_deref() == Some("chains/yominet/chain.json"))
+            .collect();
+        assert_eq!(diagnostics[0].rule, rules::REGISTRY_MISSING_L1);
+        assert_eq!(diagnostics[0].path, "$.metadata.op_denoms");
+        assert!(!diagnostics[0].is_error());
+    }
+
+    #[test]
+    fn test_registry_validate_attaches_files() {
+        let registry = registry(CHAINS, &[INITIA_ASSETS]);
+        let l1 = registry.chain_by_name("initia").unwrap();
+        let yominet = registry.chain_by_name("yominet").unwrap();
+        assert_eq!(registry.l1_for(yominet), Some(l1));
+
+        let diagnostics = registry.validate();
+        assert!(diagnostics.iter().all(|d| d.file.is_some()));
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "error[registry.fee-token-not-in-assetlist] chains/initia/chain.json $.fees.fee_tokens[1].denom: fee token `uusdc` is not an asset base in the `initia` assetlist"
+        );
+    }
+}
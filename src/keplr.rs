@@ -0,0 +1,402 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    asset::{Asset, AssetList},
+    chain::{Chain, Endpoint, FeeToken},
+    fee::GasTier,
+    hd::KeyAlgorithm,
+    registry::Registry,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeplrError {
+    UnknownChain {
+        chain_name: String,
+    },
+    MissingAssetlist {
+        chain_name: String,
+    },
+    MissingEndpoint {
+        chain_name: String,
+        api: &'static str,
+    },
+    MissingAsset {
+        chain_name: String,
+        denom: String,
+    },
+}
+
+impl fmt::Display for KeplrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeplrError::UnknownChain { chain_name } => {
+                write!(f, "chain `{chain_name}` is not in the registry")
+            }
+            KeplrError::MissingAssetlist { chain_name } => {
+                write!(f, "chain `{chain_name}` has no assetlist")
+            }
+            KeplrError::MissingEndpoint { chain_name, api } => {
+                write!(f, "chain `{chain_name}` lists no public `{api}` endpoint")
+            }
+            KeplrError::MissingAsset { chain_name, denom } => {
+                write!(
+                    f,
+                    "`{denom}` has no display unit in the `{chain_name}` assetlist"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeplrError {}
+
+/// Keplr's `ChainInfo`, as passed to `window.keplr.experimentalSuggestChain`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfo {
+    pub rpc: String,
+    pub rest: String,
+    pub chain_id: String,
+    pub chain_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_symbol_image_url: Option<String>,
+    pub bip44: Bip44,
+    pub bech32_config: Bech32Config,
+    pub currencies: Vec<Currency>,
+    pub fee_currencies: Vec<FeeCurrency>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm: Option<EvmInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Bip44 {
+    pub coin_type: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Bech32Config {
+    pub bech32_prefix_acc_addr: String,
+    pub bech32_prefix_acc_pub: String,
+    pub bech32_prefix_val_addr: String,
+    pub bech32_prefix_val_pub: String,
+    pub bech32_prefix_cons_addr: String,
+    pub bech32_prefix_cons_pub: String,
+}
+
+impl Bech32Config {
+    /// The Cosmos SDK default derivation of every prefix from the account prefix.
+    pub fn from_prefix(prefix: &str) -> Self {
+        Self {
+            bech32_prefix_acc_addr: prefix.to_string(),
+            bech32_prefix_acc_pub: format!("{prefix}pub"),
+            bech32_prefix_val_addr: format!("{prefix}valoper"),
+            bech32_prefix_val_pub: format!("{prefix}valoperpub"),
+            bech32_prefix_cons_addr: format!("{prefix}valcons"),
+            bech32_prefix_cons_pub: format!("{prefix}valconspub"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Currency {
+    pub coin_denom: String,
+    pub coin_minimal_denom: String,
+    pub coin_decimals: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin_gecko_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin_image_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeCurrency {
+    #[serde(flatten)]
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price_step: Option<GasPriceStep>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct GasPriceStep {
+    pub low: f64,
+    pub average: f64,
+    pub high: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmInfo {
+    pub chain_id: u64,
+    pub rpc: String,
+}
+
+/// The first endpoint not restricted to an `authorizedUser`.
+pub(crate) fn public_endpoint(endpoints: &[Endpoint]) -> Option<&str> {
+    endpoints
+        .iter()
+        .find(|e| e.authorized_user.is_none())
+        .map(|e| e.address.as_str())
+}
+
+impl Currency {
+    pub fn from_asset(asset: &Asset) -> Option<Self> {
+        Some(Self {
+            coin_denom: asset.symbol.clone(),
+            coin_minimal_denom: asset.base.clone(),
+            coin_decimals: asset.decimals()?,
+            coin_gecko_id: asset.coingecko_id.clone(),
+            coin_image_url: Some(asset.logo_uris.url().to_string()),
+        })
+    }
+}
+
+impl GasPriceStep {
+    pub fn from_fee_token(token: &FeeToken) -> Option<Self> {
+        Some(Self {
+            low: token.gas_price(GasTier::Low)?,
+            average: token.gas_price(GasTier::Average)?,
+            high: token.gas_price(GasTier::High)?,
+        })
+    }
+}
+
+impl ChainInfo {
+    /// Builds the chain info from a chain and its assetlist. Every fee token must be an asset
+    /// with a display unit so Keplr knows its decimals.
+    pub fn new(chain: &Chain, assetlist: &AssetList) -> Result<Self, KeplrError> {
+        let endpoint = |endpoints: &[Endpoint], api| {
+            public_endpoint(endpoints)
+                .map(str::to_string)
+                .ok_or_else(|| KeplrError::MissingEndpoint {
+                    chain_name: chain.chain_name.clone(),
+                    api,
+                })
+        };
+        let rpc = endpoint(&chain.apis.rpc, "rpc")?;
+        let rest = endpoint(&chain.apis.rest, "rest")?;
+
+        let fee_currencies = chain
+            .fees
+            .fee_tokens
+            .iter()
+            .map(|token| {
+                let currency = assetlist
+                    .assets
+                    .iter()
+                    .find(|a| a.base == token.denom)
+                    .and_then(Currency::from_asset)
+                    .ok_or_else(|| KeplrError::MissingAsset {
+                        chain_name: chain.chain_name.clone(),
+                        denom: token.denom.clone(),
+                    })?;
+                Ok(FeeCurrency {
+                    currency,
+                    gas_price_step: GasPriceStep::from_fee_token(token),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let features = match chain.key_algorithm() {
+            KeyAlgorithm::EthSecp256k1 => vec!["eth-address-gen".into(), "eth-key-sign".into()],
+            KeyAlgorithm::Secp256k1 => vec![],
+        };
+        let evm = match (chain.evm_chain_id, public_endpoint(&chain.apis.json_rpc)) {
+            (Some(chain_id), Some(rpc)) => Some(EvmInfo {
+                chain_id,
+                rpc: rpc.to_string(),
+            }),
+            _ => None,
+        };
+
+        Ok(Self {
+            rpc,
+            rest,
+            chain_id: chain.chain_id.clone(),
+            chain_name: chain.pretty_name.clone(),
+            chain_symbol_image_url: Some(chain.logo_uris.url().to_string()),
+            bip44: Bip44 {
+                coin_type: chain.slip44,
+            },
+            bech32_config: Bech32Config::from_prefix(&chain.bech32_prefix),
+            currencies: assetlist
+                .assets
+                .iter()
+                .filter_map(Currency::from_asset)
+                .collect(),
+            fee_currencies,
+            features,
+            evm,
+        })
+    }
+}
+
+impl Registry {
+    pub fn keplr_chain_info(&self, chain_name: &str) -> Result<ChainInfo, KeplrError> {
+        let chain = self
+            .chain_by_name(chain_name)
+            .ok_or_else(|| KeplrError::UnknownChain {
+                chain_name: chain_name.to_string(),
+            })?;
+        let assetlist =
+            self.assetlist_for_chain(chain)
+                .ok_or_else(|| KeplrError::MissingAssetlist {
+                    chain_name: chain_name.to_string(),
+                })?;
+        ChainInfo::new(chain, assetlist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain::ChainList, profile::ProfileList};
+
+    const CHAIN: &str = r#"{
+        "chain_id": "yominet-1",
+        "chain_name": "yominet",
+        "pretty_name": "Yominet",
+        "fees": {
+            "fee_tokens": [
+                {
+                    "denom": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06",
+                    "fixed_min_gas_price": 0.15,
+                    "low_gas_price": 0.15,
+                    "average_gas_price": 0.15,
+                    "high_gas_price": 0.4
+                }
+            ]
+        },
+        "apis": {
+            "rpc": [
+                {"address": "https://rpc-private.yominet.xyz", "authorizedUser": "skip"},
+                {"address": "https://rpc.yominet.xyz", "provider": "Initia Labs"}
+            ],
+            "rest": [{"address": "https://rest.yominet.xyz"}],
+            "json-rpc": [{"address": "https://jsonrpc.yominet.xyz"}]
+        },
+        "explorers": [],
+        "metadata": {"op_bridge_id": "11", "minitia": {"type": "minievm", "version": "v1.0.0"}},
+        "logo_URIs": {"png": "https://registry.initia.xyz/images/yominet.png"},
+        "slip44": 60,
+        "bech32_prefix": "init",
+        "network_type": "mainnet",
+        "evm_chain_id": 428962654539583
+    }"#;
+
+    const ASSETLIST: &str = r#"{
+        "$schema": "../../assetlist.schema.json",
+        "chain_name": "yominet",
+        "assets": [
+            {
+                "description": "The native token of Initia",
+                "denom_units": [
+                    {"denom": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06", "exponent": 0},
+                    {"denom": "INIT", "exponent": 6}
+                ],
+                "base": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06",
+                "display": "INIT",
+                "name": "Initia Native Token",
+                "symbol": "INIT",
+                "coingecko_id": "initia",
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+            }
+        ]
+    }"#;
+
+    fn registry() -> Registry {
+        let chains: ChainList =
+            serde_json::from_str(&format!("[{CHAIN}]")).expect("Failed to deserialize chains");
+        let assetlist = serde_json::from_str(ASSETLIST).expect("Failed to deserialize assetlist");
+        Registry::new(chains, vec![assetlist], ProfileList(vec![]))
+    }
+
+    #[test]
+    fn test_keplr_chain_info() {
+        let info = registry()
+            .keplr_chain_info("yominet")
+            .expect("Failed to build chain info");
+
+        let init = serde_json::json!({
+            "coinDenom": "INIT",
+            "coinMinimalDenom": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06",
+            "coinDecimals": 6,
+            "coinGeckoId": "initia",
+            "coinImageUrl": "https://registry.initia.xyz/images/INIT.png"
+        });
+        let mut fee_init = init.clone();
+        fee_init["gasPriceStep"] = serde_json::json!({"low": 0.15, "average": 0.15, "high": 0.4});
+        assert_eq!(
+            serde_json::to_value(&info).unwrap(),
+            serde_json::json!({
+                "rpc": "https://rpc.yominet.xyz",
+                "rest": "https://rest.yominet.xyz",
+                "chainId": "yominet-1",
+                "chainName": "Yominet",
+                "chainSymbolImageUrl": "https://registry.initia.xyz/images/yominet.png",
+                "bip44": {"coinType": 60},
+                "bech32Config": {
+                    "bech32PrefixAccAddr": "init",
+                    "bech32PrefixAccPub": "initpub",
+                    "bech32PrefixValAddr": "initvaloper",
+                    "bech32PrefixValPub": "initvaloperpub",
+                    "bech32PrefixConsAddr": "initvalcons",
+                    "bech32PrefixConsPub": "initvalconspub"
+                },
+                "currencies": [init],
+                "feeCurrencies": [fee_init],
+                "features": ["eth-address-gen", "eth-key-sign"],
+                "evm": {"chainId": 428962654539583u64, "rpc": "https://jsonrpc.yominet.xyz"}
+            })
+        );
+        let roundtrip: ChainInfo =
+            serde_json::from_value(serde_json::to_value(&info).unwrap()).unwrap();
+        assert_eq!(roundtrip, info);
+    }
+
+    #[test]
+    fn test_keplr_chain_info_errors() {
+        let mut chain: Chain = serde_json::from_str(CHAIN).unwrap();
+        let assetlist: AssetList = serde_json::from_str(ASSETLIST).unwrap();
+
+        chain.fees.fee_tokens[0].denom = "uusdc".to_string();
+        assert_eq!(
+            ChainInfo::new(&chain, &assetlist),
+            Err(KeplrError::MissingAsset {
+                chain_name: "yominet".to_string(),
+                denom: "uusdc".to_string()
+            })
+        );
+
+        chain.apis.rest.clear();
+        assert_eq!(
+            ChainInfo::new(&chain, &assetlist),
+            Err(KeplrError::MissingEndpoint {
+                chain_name: "yominet".to_string(),
+                api: "rest"
+            })
+        );
+        assert_eq!(
+            registry().keplr_chain_info("initia"),
+            Err(KeplrError::UnknownChain {
+                chain_name: "initia".to_string()
+            })
+        );
+
+        let chains: ChainList = serde_json::from_str(&format!("[{CHAIN}]")).unwrap();
+        let registry = Registry::new(chains, vec![], ProfileList(vec![]));
+        assert_eq!(
+            registry.keplr_chain_info("yominet"),
+            Err(KeplrError::MissingAssetlist {
+                chain_name: "yominet".to_string()
+            })
+        );
+    }
+}
@@ -1,4 +1,29 @@
+pub mod address;
+pub mod amount;
 pub mod asset;
 pub mod chain;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod common;
+pub mod cosmos;
+pub mod denom;
+pub mod eip3085;
+pub mod explorer;
+pub mod fee;
+pub mod format;
+pub mod hd;
+pub mod ibc_graph;
+pub mod identity;
+pub mod integrity;
+pub mod keplr;
+#[cfg(feature = "std")]
+pub mod local;
+pub mod memo;
+#[cfg(feature = "proto")]
+pub mod msg;
 pub mod profile;
+pub mod provenance;
+pub mod registry;
+pub mod route;
+pub mod token_list;
+pub mod validate;
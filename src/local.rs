@@ -0,0 +1,270 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    asset::AssetList,
+    chain::{Chain, ChainList},
+    profile::{Profile, ProfileList},
+    registry::Registry,
+};
+
+#[derive(Debug)]
+pub enum LoadErrorKind {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+#[derive(Debug)]
+pub struct LoadError {
+    pub path: PathBuf,
+    pub kind: LoadErrorKind,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            LoadErrorKind::Io(err) => write!(f, "{}: {err}", self.path.display()),
+            LoadErrorKind::Parse(err) => write!(f, "{}: {err}", self.path.display()),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            LoadErrorKind::Io(err) => Some(err),
+            LoadErrorKind::Parse(err) => Some(err),
+        }
+    }
+}
+
+/// A registry loaded from disk together with every file that could not be read or parsed.
+#[derive(Debug)]
+pub struct LoadReport {
+    pub registry: Registry,
+    pub errors: Vec<LoadError>,
+}
+
+impl LoadReport {
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Loads `chains/*/chain.json`, `chains/*/assetlist.json` and `profiles/*.json` from a local
+/// checkout of the registry repository.
+///
+/// Only a missing or unreadable `chains` directory is fatal; every other failure is collected in
+/// [`LoadReport::errors`] and the offending file is skipped.
+pub fn load_dir(root: impl AsRef<Path>) -> Result<LoadReport, LoadError> {
+    let root = root.as_ref();
+    let mut errors = Vec::new();
+
+    let chains_dir = root.join("chains");
+    let mut chains = Vec::new();
+    let mut assetlists = Vec::new();
+    for dir in sorted_entries(&chains_dir)? {
+        if !dir.is_dir() {
+            continue;
+        }
+        if let Some(chain) = read_optional::<Chain>(&dir.join("chain.json"), &mut errors) {
+            chains.push(chain);
+        }
+        if let Some(list) = read_optional::<AssetList>(&dir.join("assetlist.json"), &mut errors) {
+            assetlists.push(list);
+        }
+    }
+
+    let profiles_dir = root.join("profiles");
+    let mut profiles = Vec::new();
+    match sorted_entries(&profiles_dir) {
+        Ok(files) => {
+            for file in files
+                .iter()
+                .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+            {
+                if let Some(profile) = read_optional::<Profile>(file, &mut errors) {
+                    profiles.push(profile);
+                }
+            }
+        }
+        Err(LoadError {
+            kind: LoadErrorKind::Io(err),
+            ..
+        }) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => errors.push(err),
+    }
+
+    Ok(LoadReport {
+        registry: Registry::new(ChainList(chains), assetlists, ProfileList(profiles)),
+        errors,
+    })
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, LoadError> {
+    let io_error = |err| LoadError {
+        path: dir.to_path_buf(),
+        kind: LoadErrorKind::Io(err),
+    };
+    let mut entries = fs::read_dir(dir)
+        .map_err(io_error)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_error)?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Reads and parses `path`, treating a missing file as absent rather than as an error.
+fn read_optional<T: DeserializeOwned>(path: &Path, errors: &mut Vec<LoadError>) -> Option<T> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            errors.push(LoadError {
+                path: path.to_path_buf(),
+                kind: LoadErrorKind::Io(err),
+            });
+            return None;
+        }
+    };
+    match serde_json::from_str(&content) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            errors.push(LoadError {
+                path: path.to_path_buf(),
+                kind: LoadErrorKind::Parse(err),
+            });
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("initia-registry-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).expect("Failed to create temp dir");
+            Self(path)
+        }
+
+        fn write(&self, relative: &str, content: &str) {
+            let path = self.0.join(relative);
+            fs::create_dir_all(path.parent().unwrap()).expect("Failed to create dir");
+            fs::write(path, content).expect("Failed to write file");
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    const CHAIN_JSON: &str = r#"{
+        "chain_id": "interwoven-1",
+        "chain_name": "initia",
+        "pretty_name": "Initia",
+        "fees": {"fee_tokens": [{"denom": "uinit"}]},
+        "apis": {},
+        "explorers": [],
+        "metadata": {"is_l1": true},
+        "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"},
+        "slip44": 60,
+        "bech32_prefix": "init",
+        "network_type": "mainnet"
+    }"#;
+
+    const ASSETLIST_JSON: &str = r#"{
+        "$schema": "../../assetlist.schema.json",
+        "chain_name": "initia",
+        "assets": [
+            {
+                "description": "The native token of Initia",
+                "denom_units": [{"denom": "uinit", "exponent": 0}],
+                "base": "uinit",
+                "display": "INIT",
+                "name": "Initia Native Token",
+                "symbol": "INIT",
+                "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+            }
+        ]
+    }"#;
+
+    const PROFILE_JSON: &str = r##"{
+        "name": "initia",
+        "pretty_name": "Initia",
+        "category": "Infrastructure",
+        "description": "Initia L1",
+        "logo": "https://registry.initia.xyz/images/INIT.png",
+        "color": "#FFFFFF",
+        "status": "live",
+        "social": {"website": "https://initia.xyz", "twitter": "https://x.com/initia"}
+    }"##;
+
+    #[test]
+    fn test_load_dir() {
+        let dir = TempDir::new("load");
+        dir.write("chains/initia/chain.json", CHAIN_JSON);
+        dir.write("chains/initia/assetlist.json", ASSETLIST_JSON);
+        dir.write("profiles/initia.json", PROFILE_JSON);
+        dir.write("profiles/README.md", "not a profile");
+
+        let report = load_dir(&dir.0).expect("Failed to load registry");
+
+        assert!(report.is_clean(), "unexpected errors: {:?}", report.errors);
+        assert!(report.registry.chain_by_name("initia").is_some());
+        assert!(report.registry.asset("initia", "uinit").is_some());
+        assert!(report.registry.profile("initia").is_some());
+    }
+
+    #[test]
+    fn test_load_dir_collects_parse_errors() {
+        let dir = TempDir::new("errors");
+        dir.write("chains/initia/chain.json", CHAIN_JSON);
+        dir.write("chains/broken/chain.json", r#"{"chain_id": "broken-1"}"#);
+        dir.write("chains/broken/assetlist.json", "{ not json");
+        dir.write("profiles/initia.json", PROFILE_JSON);
+
+        let report = load_dir(&dir.0).expect("Failed to load registry");
+
+        assert_eq!(report.registry.chains().len(), 1);
+        assert_eq!(report.registry.profiles().len(), 1);
+        assert_eq!(report.errors.len(), 2);
+        assert!(
+            report
+                .errors
+                .iter()
+                .all(|e| matches!(e.kind, LoadErrorKind::Parse(_)))
+        );
+        assert_eq!(
+            report.errors[0].path,
+            dir.0.join("chains/broken/chain.json")
+        );
+        assert!(report.errors[0].to_string().contains("missing field"));
+        assert_eq!(
+            report.errors[1].path,
+            dir.0.join("chains/broken/assetlist.json")
+        );
+    }
+
+    #[test]
+    fn test_load_dir_without_chains() {
+        let dir = TempDir::new("empty");
+
+        let err = load_dir(&dir.0).expect_err("Expected missing chains dir to fail");
+        assert_eq!(err.path, dir.0.join("chains"));
+        assert!(matches!(err.kind, LoadErrorKind::Io(_)));
+    }
+}
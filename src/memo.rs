@@ -0,0 +1,573 @@
+use std::fmt;
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    amount::Coin,
+    chain::{Chain, IbcChannel, MinitiaType},
+    ibc_graph::IbcGraph,
+    registry::Registry,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MemoError {
+    NoHops,
+    UnknownChain {
+        chain_name: String,
+    },
+    MissingChannel {
+        from: String,
+        to: String,
+    },
+    WrongVm {
+        chain_name: String,
+        expected: MinitiaType,
+        found: MinitiaType,
+    },
+    NoHooks {
+        chain_name: String,
+    },
+}
+
+impl fmt::Display for MemoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoError::NoHops => f.write_str("a forward route needs at least one hop"),
+            MemoError::UnknownChain { chain_name } => {
+                write!(f, "chain `{chain_name}` is not in the registry")
+            }
+            MemoError::MissingChannel { from, to } => {
+                write!(f, "`{from}` lists no transfer channel to `{to}`")
+            }
+            MemoError::WrongVm {
+                chain_name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{chain_name}` runs {found:?}, not {expected:?}, so it cannot take this hook"
+            ),
+            MemoError::NoHooks { chain_name } => {
+                write!(
+                    f,
+                    "`{chain_name}` is not an Initia chain and does not support hooks"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MemoError {}
+
+/// The packet-forward-middleware memo, `{"forward": {...}}`, nested through `next`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMemo {
+    pub forward: Forward,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Forward {
+    pub receiver: String,
+    pub port: String,
+    pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<ForwardMemo>>,
+}
+
+impl ForwardMemo {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("memo serializes to JSON")
+    }
+}
+
+/// The first transfer of a forwarded route and the memo that carries the remaining hops.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardTransfer {
+    pub source_port: String,
+    pub source_channel: String,
+    pub receiver: String,
+    pub memo: Option<ForwardMemo>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ForwardHop {
+    chain_name: String,
+    receiver: String,
+    timeout: Option<String>,
+    retries: Option<u8>,
+}
+
+/// Builds a multi-hop transfer from `source_chain` through each `hop` in order. Timeouts and
+/// retries apply to the forward into the hop they are set on, so they are ignored on the
+/// first hop, which is the plain `MsgTransfer`.
+#[derive(Clone, Debug)]
+pub struct ForwardMemoBuilder<'a> {
+    registry: &'a Registry,
+    source_chain: String,
+    hops: Vec<ForwardHop>,
+}
+
+impl<'a> ForwardMemoBuilder<'a> {
+    pub fn new(registry: &'a Registry, source_chain: impl Into<String>) -> Self {
+        Self {
+            registry,
+            source_chain: source_chain.into(),
+            hops: vec![],
+        }
+    }
+
+    pub fn hop(mut self, chain_name: impl Into<String>, receiver: impl Into<String>) -> Self {
+        self.hops.push(ForwardHop {
+            chain_name: chain_name.into(),
+            receiver: receiver.into(),
+            timeout: None,
+            retries: None,
+        });
+        self
+    }
+
+    /// Sets the forward timeout of the last hop, in PFM duration syntax such as `10m`.
+    pub fn timeout(mut self, timeout: impl Into<String>) -> Self {
+        if let Some(hop) = self.hops.last_mut() {
+            hop.timeout = Some(timeout.into());
+        }
+        self
+    }
+
+    pub fn retries(mut self, retries: u8) -> Self {
+        if let Some(hop) = self.hops.last_mut() {
+            hop.retries = Some(retries);
+        }
+        self
+    }
+
+    pub fn build(&self) -> Result<ForwardTransfer, MemoError> {
+        let graph = IbcGraph::transfer(self.registry);
+        let mut chains = vec![self.chain(&self.source_chain)?];
+        for hop in &self.hops {
+            chains.push(self.chain(&hop.chain_name)?);
+        }
+        let channels = chains
+            .windows(2)
+            .map(|pair| {
+                graph
+                    .channels(&pair[0].chain_id)
+                    .iter()
+                    .find(|e| e.counterparty_chain_id() == pair[1].chain_id)
+                    .map(|e| e.channel)
+                    .ok_or_else(|| MemoError::MissingChannel {
+                        from: pair[0].chain_name.clone(),
+                        to: pair[1].chain_name.clone(),
+                    })
+            })
+            .collect::<Result<Vec<&IbcChannel>, _>>()?;
+
+        let (first, forwarded) = self.hops.split_first().ok_or(MemoError::NoHops)?;
+        let memo = forwarded
+            .iter()
+            .zip(&channels[1..])
+            .rev()
+            .fold(None, |next, (hop, channel)| {
+                Some(ForwardMemo {
+                    forward: Forward {
+                        receiver: hop.receiver.clone(),
+                        port: channel.port_id.clone(),
+                        channel: channel.channel_id.clone(),
+                        timeout: hop.timeout.clone(),
+                        retries: hop.retries,
+                        next: next.map(Box::new),
+                    },
+                })
+            });
+
+        Ok(ForwardTransfer {
+            source_port: channels[0].port_id.clone(),
+            source_channel: channels[0].channel_id.clone(),
+            receiver: first.receiver.clone(),
+            memo,
+        })
+    }
+
+    fn chain(&self, chain_name: &str) -> Result<&'a Chain, MemoError> {
+        self.registry
+            .chain_by_name(chain_name)
+            .ok_or_else(|| MemoError::UnknownChain {
+                chain_name: chain_name.to_string(),
+            })
+    }
+}
+
+/// The IBC-hooks memo, shaped by the VM of the receiving chain.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HookMemo {
+    Evm { message: EvmMessage },
+    Move { message: MoveMessage },
+    Wasm { message: WasmMessage },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EvmMessage {
+    pub contract_addr: String,
+    /// `0x`-prefixed hex calldata.
+    pub input: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MoveMessage {
+    pub module_address: String,
+    pub module_name: String,
+    pub function_name: String,
+    pub type_args: Vec<String>,
+    /// Base64 of each BCS-encoded argument.
+    pub args: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WasmMessage {
+    pub contract: String,
+    pub msg: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub funds: Vec<Coin>,
+}
+
+impl HookMemo {
+    /// The `MsgTransfer` receiver the hook middleware expects alongside this memo.
+    pub fn receiver(&self) -> String {
+        match self {
+            HookMemo::Evm { message } => message.contract_addr.clone(),
+            HookMemo::Move { message } => format!(
+                "{}::{}::{}",
+                message.module_address, message.module_name, message.function_name
+            ),
+            HookMemo::Wasm { message } => message.contract.clone(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("memo serializes to JSON")
+    }
+}
+
+impl Chain {
+    /// The VM that executes IBC hooks on this chain: the `minitia` type of a rollup, or MoveVM
+    /// on the Initia L1. `None` for any other chain, whose hooks this crate does not know.
+    pub fn hook_vm(&self) -> Option<MinitiaType> {
+        match &self.metadata.minitia {
+            Some(minitia) => Some(minitia.ty.clone()),
+            None if self.metadata.is_l1 == Some(true) => Some(MinitiaType::MiniMove),
+            None => None,
+        }
+    }
+}
+
+/// Builds hook memos for contract calls on a destination chain, rejecting calls for a VM the
+/// chain does not run.
+#[derive(Clone, Debug)]
+pub struct HookMemoBuilder<'a> {
+    chain: &'a Chain,
+}
+
+impl<'a> HookMemoBuilder<'a> {
+    pub fn new(chain: &'a Chain) -> Self {
+        Self { chain }
+    }
+
+    pub fn evm_call(
+        &self,
+        contract_addr: impl Into<String>,
+        input: &[u8],
+    ) -> Result<HookMemo, MemoError> {
+        self.expect_vm(MinitiaType::MiniEVM)?;
+        Ok(HookMemo::Evm {
+            message: EvmMessage {
+                contract_addr: contract_addr.into(),
+                input: format!("0x{}", hex::encode(input)),
+            },
+        })
+    }
+
+    /// Calls a Move entry function with BCS-encoded `args`.
+    pub fn move_call(
+        &self,
+        module_address: impl Into<String>,
+        module_name: impl Into<String>,
+        function_name: impl Into<String>,
+        type_args: Vec<String>,
+        args: &[Vec<u8>],
+    ) -> Result<HookMemo, MemoError> {
+        self.expect_vm(MinitiaType::MiniMove)?;
+        Ok(HookMemo::Move {
+            message: MoveMessage {
+                module_address: module_address.into(),
+                module_name: module_name.into(),
+                function_name: function_name.into(),
+                type_args,
+                args: args.iter().map(|a| BASE64.encode(a)).collect(),
+            },
+        })
+    }
+
+    pub fn wasm_execute(
+        &self,
+        contract: impl Into<String>,
+        msg: serde_json::Value,
+        funds: Vec<Coin>,
+    ) -> Result<HookMemo, MemoError> {
+        self.expect_vm(MinitiaType::MiniWasm)?;
+        Ok(HookMemo::Wasm {
+            message: WasmMessage {
+                contract: contract.into(),
+                msg,
+                funds,
+            },
+        })
+    }
+
+    fn expect_vm(&self, expected: MinitiaType) -> Result<(), MemoError> {
+        let found = self.chain.hook_vm().ok_or_else(|| MemoError::NoHooks {
+            chain_name: self.chain.chain_name.clone(),
+        })?;
+        if found != expected {
+            return Err(MemoError::WrongVm {
+                chain_name: self.chain.chain_name.clone(),
+                expected,
+                found,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain::ChainList, profile::ProfileList};
+
+    fn chain(chain_id: &str, chain_name: &str, channels: &[(&str, &str)]) -> String {
+        let channels: Vec<_> = channels
+            .iter()
+            .map(|(counterparty, channel)| {
+                format!(
+                    r#"{{"chain_id": "{counterparty}", "port_id": "transfer", "channel_id": "{channel}", "version": "ics20-1"}}"#
+                )
+            })
+            .collect();
+        format!(
+            r#"{{
+                "chain_id": "{chain_id}",
+                "chain_name": "{chain_name}",
+                "pretty_name": "{chain_name}",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{"ibc_channels": [{}]}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"
+            }}"#,
+            channels.join(",")
+        )
+    }
+
+    fn registry() -> Registry {
+        let chains = [
+            chain("noble-1", "noble", &[("interwoven-1", "channel-129")]),
+            chain(
+                "interwoven-1",
+                "initia",
+                &[("noble-1", "channel-3"), ("yominet-1", "channel-25")],
+            ),
+            chain("yominet-1", "yominet", &[("interwoven-1", "channel-0")]),
+        ];
+        let chains: ChainList = serde_json::from_str(&format!("[{}]", chains.join(",")))
+            .expect("Failed to deserialize chains");
+        Registry::new(chains, vec![], ProfileList(vec![]))
+    }
+
+    #[test]
+    fn test_forward_memo() {
+        let registry = registry();
+
+        let transfer = ForwardMemoBuilder::new(&registry, "noble")
+            .hop("initia", "init1forwarder")
+            .hop("yominet", "init1receiver")
+            .timeout("10m")
+            .retries(2)
+            .build()
+            .expect("Failed to build forward memo");
+
+        assert_eq!(transfer.source_port, "transfer");
+        assert_eq!(transfer.source_channel, "channel-129");
+        assert_eq!(transfer.receiver, "init1forwarder");
+        assert_eq!(
+            transfer.memo.unwrap().to_json(),
+            r#"{"forward":{"receiver":"init1receiver","port":"transfer","channel":"channel-25","timeout":"10m","retries":2}}"#
+        );
+    }
+
+    #[test]
+    fn test_nested_forward_memo() {
+        let registry = registry();
+
+        let transfer = ForwardMemoBuilder::new(&registry, "yominet")
+            .hop("initia", "pfm")
+            .hop("noble", "pfm")
+            .hop("initia", "init1receiver")
+            .build()
+            .unwrap();
+
+        assert_eq!(transfer.source_channel, "channel-0");
+        assert_eq!(
+            serde_json::to_value(transfer.memo.unwrap()).unwrap(),
+            serde_json::json!({
+                "forward": {
+                    "receiver": "pfm",
+                    "port": "transfer",
+                    "channel": "channel-3",
+                    "next": {
+                        "forward": {
+                            "receiver": "init1receiver",
+                            "port": "transfer",
+                            "channel": "channel-129"
+                        }
+                    }
+                }
+            })
+        );
+
+        let direct = ForwardMemoBuilder::new(&registry, "initia")
+            .hop("noble", "noble1receiver")
+            .build()
+            .unwrap();
+        assert_eq!(direct.memo, None);
+    }
+
+    #[test]
+    fn test_forward_memo_errors() {
+        let registry = registry();
+
+        assert_eq!(
+            ForwardMemoBuilder::new(&registry, "initia").build(),
+            Err(MemoError::NoHops)
+        );
+        assert_eq!(
+            ForwardMemoBuilder::new(&registry, "noble")
+                .hop("yominet", "init1receiver")
+                .build(),
+            Err(MemoError::MissingChannel {
+                from: "noble".to_string(),
+                to: "yominet".to_string()
+            })
+        );
+        assert_eq!(
+            ForwardMemoBuilder::new(&registry, "initia")
+                .hop("osmosis", "osmo1receiver")
+                .build(),
+            Err(MemoError::UnknownChain {
+                chain_name: "osmosis".to_string()
+            })
+        );
+    }
+
+    fn vm_chain(minitia: &str) -> Chain {
+        serde_json::from_str(&format!(
+            r#"{{
+                "chain_id": "rollup-1",
+                "chain_name": "rollup",
+                "pretty_name": "Rollup",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{{minitia}}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"
+            }}"#
+        ))
+        .expect("Failed to deserialize chain")
+    }
+
+    #[test]
+    fn test_hook_memos() {
+        let evm = vm_chain(r#""minitia": {"type": "minievm", "version": "v1.0.0"}"#);
+        let memo = HookMemoBuilder::new(&evm)
+            .evm_call("0xE1Ff7038eAAAF027031688E1535a055B2Bac2546", &[0xa9, 0x05])
+            .expect("Failed to build EVM hook");
+        assert_eq!(
+            memo.to_json(),
+            r#"{"evm":{"message":{"contract_addr":"0xE1Ff7038eAAAF027031688E1535a055B2Bac2546","input":"0xa905"}}}"#
+        );
+        assert_eq!(
+            memo.receiver(),
+            "0xE1Ff7038eAAAF027031688E1535a055B2Bac2546"
+        );
+
+        let l1 = vm_chain(r#""is_l1": true"#);
+        let memo = HookMemoBuilder::new(&l1)
+            .move_call(
+                "0x1",
+                "dex",
+                "swap_script",
+                vec!["0x1::coin::Coin".to_string()],
+                &[vec![1, 0, 0, 0, 0, 0, 0, 0]],
+            )
+            .unwrap();
+        assert_eq!(
+            memo.to_json(),
+            r#"{"move":{"message":{"module_address":"0x1","module_name":"dex","function_name":"swap_script","type_args":["0x1::coin::Coin"],"args":["AQAAAAAAAAA="]}}}"#
+        );
+        assert_eq!(memo.receiver(), "0x1::dex::swap_script");
+
+        let wasm = vm_chain(r#""minitia": {"type": "miniwasm", "version": "v1.0.0"}"#);
+        let memo = HookMemoBuilder::new(&wasm)
+            .wasm_execute(
+                "init1contract",
+                serde_json::json!({"swap": {}}),
+                vec![Coin::new(5, "uinit")],
+            )
+            .unwrap();
+        assert_eq!(
+            memo.to_json(),
+            r#"{"wasm":{"message":{"contract":"init1contract","msg":{"swap":{}},"funds":[{"denom":"uinit","amount":"5"}]}}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<HookMemo>(&memo.to_json()).unwrap(),
+            memo
+        );
+    }
+
+    #[test]
+    fn test_hook_memo_wrong_vm() {
+        let wasm = vm_chain(r#""minitia": {"type": "miniwasm", "version": "v1.0.0"}"#);
+
+        assert_eq!(
+            HookMemoBuilder::new(&wasm).evm_call("0x1", &[]),
+            Err(MemoError::WrongVm {
+                chain_name: "rollup".to_string(),
+                expected: MinitiaType::MiniEVM,
+                found: MinitiaType::MiniWasm,
+            })
+        );
+    }
+
+    #[test]
+    fn test_hook_memo_no_hooks() {
+        let external = vm_chain("");
+        assert_eq!(external.hook_vm(), None);
+
+        assert_eq!(
+            HookMemoBuilder::new(&external).move_call("0x1", "dex", "swap_script", vec![], &[]),
+            Err(MemoError::NoHooks {
+                chain_name: "rollup".to_string(),
+            })
+        );
+    }
+}
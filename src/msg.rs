@@ -0,0 +1,440 @@
+use std::fmt;
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use prost::Message;
+use serde_json::{Map, Value, json};
+
+use crate::{
+    amount,
+    asset::Asset,
+    chain::{Chain, IbcChannel},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MsgError {
+    MissingBridgeId { chain_name: String },
+    DenomNotBridged { chain_name: String, denom: String },
+}
+
+impl fmt::Display for MsgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MsgError::MissingBridgeId { chain_name } => {
+                write!(f, "chain `{chain_name}` has no valid `op_bridge_id`")
+            }
+            MsgError::DenomNotBridged { chain_name, denom } => {
+                write!(f, "`{denom}` is not in the `op_denoms` of `{chain_name}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MsgError {}
+
+/// A protobuf message with its `Any` type URL and legacy Amino name, for signing in either
+/// `SIGN_MODE_DIRECT` or `SIGN_MODE_LEGACY_AMINO_JSON`.
+pub trait TxMsg: Message + Sized {
+    const TYPE_URL: &'static str;
+    const AMINO_TYPE: &'static str;
+
+    /// The Amino JSON `value`, with empty fields omitted and 64-bit integers as strings.
+    fn amino_value(&self) -> Value;
+
+    fn to_any(&self) -> Any {
+        Any {
+            type_url: Self::TYPE_URL.to_string(),
+            value: self.encode_to_vec(),
+        }
+    }
+
+    fn to_amino_json(&self) -> Value {
+        json!({ "type": Self::AMINO_TYPE, "value": self.amino_value() })
+    }
+}
+
+/// `google.protobuf.Any`.
+#[derive(Clone, PartialEq, Message)]
+pub struct Any {
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+/// `cosmos.base.v1beta1.Coin`.
+#[derive(Clone, PartialEq, Eq, Message)]
+pub struct Coin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+impl From<&amount::Coin> for Coin {
+    fn from(coin: &amount::Coin) -> Self {
+        Self {
+            denom: coin.denom.clone(),
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+impl Coin {
+    fn amino_value(&self) -> Value {
+        json!({ "denom": self.denom, "amount": self.amount })
+    }
+}
+
+/// `ibc.core.client.v1.Height`.
+#[derive(Clone, Copy, PartialEq, Eq, Message)]
+pub struct Height {
+    #[prost(uint64, tag = "1")]
+    pub revision_number: u64,
+    #[prost(uint64, tag = "2")]
+    pub revision_height: u64,
+}
+
+/// `ibc.applications.transfer.v1.MsgTransfer`.
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<Coin>,
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: Option<Height>,
+    /// Unix time in nanoseconds.
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: String,
+}
+
+impl MsgTransfer {
+    /// Sends `amount` of the asset's base denom over `channel`, the sending chain's end.
+    pub fn new(
+        channel: &IbcChannel,
+        asset: &Asset,
+        amount: u128,
+        sender: impl Into<String>,
+        receiver: impl Into<String>,
+        timeout_timestamp: u64,
+    ) -> Self {
+        Self {
+            source_port: channel.port_id.clone(),
+            source_channel: channel.channel_id.clone(),
+            token: Some(Coin::from(&amount::Coin::new(amount, asset.base.clone()))),
+            sender: sender.into(),
+            receiver: receiver.into(),
+            timeout_height: None,
+            timeout_timestamp,
+            memo: String::new(),
+        }
+    }
+
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = memo.into();
+        self
+    }
+}
+
+impl TxMsg for MsgTransfer {
+    const TYPE_URL: &'static str = "/ibc.applications.transfer.v1.MsgTransfer";
+    const AMINO_TYPE: &'static str = "cosmos-sdk/MsgTransfer";
+
+    fn amino_value(&self) -> Value {
+        let mut value = Map::new();
+        value.insert("source_port".into(), json!(self.source_port));
+        value.insert("source_channel".into(), json!(self.source_channel));
+        if let Some(token) = &self.token {
+            value.insert("token".into(), token.amino_value());
+        }
+        value.insert("sender".into(), json!(self.sender));
+        value.insert("receiver".into(), json!(self.receiver));
+        // `timeout_height` is marked `dont_omitempty`, so a zero height is still an object.
+        let mut height = Map::new();
+        let timeout_height = self.timeout_height.unwrap_or_default();
+        if timeout_height.revision_number != 0 {
+            height.insert(
+                "revision_number".into(),
+                json!(timeout_height.revision_number.to_string()),
+            );
+        }
+        if timeout_height.revision_height != 0 {
+            height.insert(
+                "revision_height".into(),
+                json!(timeout_height.revision_height.to_string()),
+            );
+        }
+        value.insert("timeout_height".into(), Value::Object(height));
+        if self.timeout_timestamp != 0 {
+            value.insert(
+                "timeout_timestamp".into(),
+                json!(self.timeout_timestamp.to_string()),
+            );
+        }
+        if !self.memo.is_empty() {
+            value.insert("memo".into(), json!(self.memo));
+        }
+        Value::Object(value)
+    }
+}
+
+/// `opinit.ophost.v1.MsgInitiateTokenDeposit`, sent on the L1.
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgInitiateTokenDeposit {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(uint64, tag = "2")]
+    pub bridge_id: u64,
+    #[prost(string, tag = "3")]
+    pub to: String,
+    #[prost(message, optional, tag = "4")]
+    pub amount: Option<Coin>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub data: Vec<u8>,
+}
+
+impl MsgInitiateTokenDeposit {
+    /// Deposits `amount` of an L1 asset into the `l2` chain's bridge. The asset must be one of
+    /// the L2's `op_denoms`.
+    pub fn new(
+        l2: &Chain,
+        asset: &Asset,
+        amount: u128,
+        sender: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Result<Self, MsgError> {
+        let bridge_id = l2
+            .metadata
+            .op_bridge_id
+            .as_ref()
+            .and_then(|id| id.parse().ok())
+            .ok_or_else(|| MsgError::MissingBridgeId {
+                chain_name: l2.chain_name.clone(),
+            })?;
+        if !l2.metadata.op_denoms.contains(&asset.base) {
+            return Err(MsgError::DenomNotBridged {
+                chain_name: l2.chain_name.clone(),
+                denom: asset.base.clone(),
+            });
+        }
+        Ok(Self {
+            sender: sender.into(),
+            bridge_id,
+            to: to.into(),
+            amount: Some(Coin::from(&amount::Coin::new(amount, asset.base.clone()))),
+            data: vec![],
+        })
+    }
+}
+
+impl TxMsg for MsgInitiateTokenDeposit {
+    const TYPE_URL: &'static str = "/opinit.ophost.v1.MsgInitiateTokenDeposit";
+    const AMINO_TYPE: &'static str = "ophost/MsgInitiateTokenDeposit";
+
+    fn amino_value(&self) -> Value {
+        let mut value = Map::new();
+        value.insert("sender".into(), json!(self.sender));
+        value.insert("bridge_id".into(), json!(self.bridge_id.to_string()));
+        value.insert("to".into(), json!(self.to));
+        if let Some(amount) = &self.amount {
+            value.insert("amount".into(), amount.amino_value());
+        }
+        if !self.data.is_empty() {
+            value.insert("data".into(), json!(BASE64.encode(&self.data)));
+        }
+        Value::Object(value)
+    }
+}
+
+/// `opinit.opchild.v1.MsgInitiateTokenWithdrawal`, sent on the L2.
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgInitiateTokenWithdrawal {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub to: String,
+    #[prost(message, optional, tag = "3")]
+    pub amount: Option<Coin>,
+}
+
+impl MsgInitiateTokenWithdrawal {
+    /// Withdraws `amount` of an L2 asset, whose base is the bridged `l2/...` denom.
+    pub fn new(
+        asset: &Asset,
+        amount: u128,
+        sender: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            to: to.into(),
+            amount: Some(Coin::from(&amount::Coin::new(amount, asset.base.clone()))),
+        }
+    }
+}
+
+impl TxMsg for MsgInitiateTokenWithdrawal {
+    const TYPE_URL: &'static str = "/opinit.opchild.v1.MsgInitiateTokenWithdrawal";
+    const AMINO_TYPE: &'static str = "opchild/MsgInitiateTokenWithdrawal";
+
+    fn amino_value(&self) -> Value {
+        let mut value = Map::new();
+        value.insert("sender".into(), json!(self.sender));
+        value.insert("to".into(), json!(self.to));
+        if let Some(amount) = &self.amount {
+            value.insert("amount".into(), amount.amino_value());
+        }
+        Value::Object(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(base: &str) -> Asset {
+        serde_json::from_str(&format!(
+            r#"{{
+                "description": "{base}",
+                "denom_units": [{{"denom": "{base}", "exponent": 0}}],
+                "base": "{base}",
+                "display": "{base}",
+                "name": "{base}",
+                "symbol": "{base}",
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}}
+            }}"#
+        ))
+        .expect("Failed to deserialize asset")
+    }
+
+    fn l2(metadata: &str) -> Chain {
+        serde_json::from_str(&format!(
+            r#"{{
+                "chain_id": "yominet-1",
+                "chain_name": "yominet",
+                "pretty_name": "Yominet",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{{metadata}}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/yominet.png"}},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"
+            }}"#
+        ))
+        .expect("Failed to deserialize chain")
+    }
+
+    #[test]
+    fn test_msg_transfer() {
+        let channel = IbcChannel {
+            chain_id: "yominet-1".to_string(),
+            channel_id: "channel-25".to_string(),
+            port_id: "transfer".to_string(),
+            version: "ics20-1".to_string(),
+        };
+        let msg = MsgTransfer::new(
+            &channel,
+            &asset("uinit"),
+            1_500_000,
+            "init1sender",
+            "init1receiver",
+            1_700_000_000_000_000_000,
+        )
+        .with_memo("hello");
+
+        let any = msg.to_any();
+        assert_eq!(any.type_url, "/ibc.applications.transfer.v1.MsgTransfer");
+        assert_eq!(&any.value[..10], b"\x0a\x08transfer");
+        assert_eq!(MsgTransfer::decode(any.value.as_slice()).unwrap(), msg);
+
+        assert_eq!(
+            msg.to_amino_json(),
+            json!({
+                "type": "cosmos-sdk/MsgTransfer",
+                "value": {
+                    "source_port": "transfer",
+                    "source_channel": "channel-25",
+                    "token": {"denom": "uinit", "amount": "1500000"},
+                    "sender": "init1sender",
+                    "receiver": "init1receiver",
+                    "timeout_height": {},
+                    "timeout_timestamp": "1700000000000000000",
+                    "memo": "hello"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_op_messages() {
+        let yominet = l2(r#""op_bridge_id": "11", "op_denoms": ["uinit"]"#);
+
+        let deposit =
+            MsgInitiateTokenDeposit::new(&yominet, &asset("uinit"), 10, "init1a", "init1b")
+                .expect("Failed to build deposit");
+        assert_eq!(deposit.bridge_id, 11);
+        assert_eq!(
+            deposit.encode_to_vec(),
+            b"\x0a\x06init1a\x10\x0b\x1a\x06init1b\x22\x0b\x0a\x05uinit\x12\x0210"
+        );
+        assert_eq!(
+            deposit.to_amino_json(),
+            json!({
+                "type": "ophost/MsgInitiateTokenDeposit",
+                "value": {
+                    "sender": "init1a",
+                    "bridge_id": "11",
+                    "to": "init1b",
+                    "amount": {"denom": "uinit", "amount": "10"}
+                }
+            })
+        );
+
+        let withdrawal = MsgInitiateTokenWithdrawal::new(&asset("l2/abc"), 10, "init1b", "init1a");
+        assert_eq!(
+            withdrawal.to_any().type_url,
+            "/opinit.opchild.v1.MsgInitiateTokenWithdrawal"
+        );
+        assert_eq!(
+            withdrawal.to_amino_json()["type"],
+            "opchild/MsgInitiateTokenWithdrawal"
+        );
+        assert_eq!(
+            MsgInitiateTokenWithdrawal::decode(withdrawal.encode_to_vec().as_slice()).unwrap(),
+            withdrawal
+        );
+    }
+
+    #[test]
+    fn test_deposit_errors() {
+        assert_eq!(
+            MsgInitiateTokenDeposit::new(&l2(""), &asset("uinit"), 1, "a", "b"),
+            Err(MsgError::MissingBridgeId {
+                chain_name: "yominet".to_string()
+            })
+        );
+        assert_eq!(
+            MsgInitiateTokenDeposit::new(
+                &l2(r#""op_bridge_id": "11", "op_denoms": ["uinit"]"#),
+                &asset("uusdc"),
+                1,
+                "a",
+                "b"
+            ),
+            Err(MsgError::DenomNotBridged {
+                chain_name: "yominet".to_string(),
+                denom: "uusdc".to_string()
+            })
+        );
+    }
+}
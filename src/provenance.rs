@@ -0,0 +1,282 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    asset::{Asset, Trace},
+    registry::Registry,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProvenanceStep<'a> {
+    pub chain_name: &'a str,
+    pub asset: &'a Asset,
+    /// The trace hop that brought the asset onto this chain, or `None` at the origin.
+    pub via: Option<&'a Trace>,
+}
+
+/// The hops of an asset, starting at the queried asset and ending at its native origin.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Provenance<'a> {
+    pub steps: Vec<ProvenanceStep<'a>>,
+}
+
+impl<'a> Provenance<'a> {
+    pub fn origin(&self) -> &ProvenanceStep<'a> {
+        self.steps.last().expect("provenance always has a step")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProvenanceError {
+    UnknownChain { chain_name: String },
+    MissingAsset { chain_name: String, denom: String },
+    Cycle { chain_name: String, denom: String },
+}
+
+impl fmt::Display for ProvenanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProvenanceError::UnknownChain { chain_name } => {
+                write!(f, "chain `{chain_name}` is not in the registry")
+            }
+            ProvenanceError::MissingAsset { chain_name, denom } => {
+                write!(f, "`{denom}` is not in the `{chain_name}` assetlist")
+            }
+            ProvenanceError::Cycle { chain_name, denom } => {
+                write!(f, "traces loop back to `{denom}` on `{chain_name}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProvenanceError {}
+
+impl Registry {
+    /// Follows the last trace of each asset into the counterparty's assetlist until an asset
+    /// without traces is reached.
+    pub fn provenance<'a>(
+        &'a self,
+        chain_name: &str,
+        asset: &'a Asset,
+    ) -> Result<Provenance<'a>, ProvenanceError> {
+        let chain =
+            self.chain_by_name(chain_name)
+                .ok_or_else(|| ProvenanceError::UnknownChain {
+                    chain_name: chain_name.to_string(),
+                })?;
+
+        let mut steps = Vec::new();
+        let mut visited = HashSet::new();
+        let mut current = (chain.chain_name.as_str(), asset);
+        loop {
+            let (chain_name, asset) = current;
+            if !visited.insert((chain_name, asset.base.as_str())) {
+                return Err(ProvenanceError::Cycle {
+                    chain_name: chain_name.to_string(),
+                    denom: asset.base.clone(),
+                });
+            }
+
+            let via = asset.traces.last();
+            steps.push(ProvenanceStep {
+                chain_name,
+                asset,
+                via,
+            });
+            let Some(trace) = via else {
+                return Ok(Provenance { steps });
+            };
+
+            let next_chain = self.chain_by_name(trace.chain_name()).ok_or_else(|| {
+                ProvenanceError::UnknownChain {
+                    chain_name: trace.chain_name().to_string(),
+                }
+            })?;
+            let next_asset = self
+                .asset(&next_chain.chain_name, trace.base_denom())
+                .ok_or_else(|| ProvenanceError::MissingAsset {
+                    chain_name: next_chain.chain_name.clone(),
+                    denom: trace.base_denom().to_string(),
+                })?;
+            current = (next_chain.chain_name.as_str(), next_asset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{asset::AssetList, chain::ChainList, profile::ProfileList};
+
+    const L2_INIT: &str = "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06";
+    const IBC_INIT_YOMINET: &str =
+        "ibc/07FFEEEE36370551554531FF7189EB2D3353B187CB6CE715DA1291C3A4B4EAC2";
+
+    fn chain(chain_id: &str, chain_name: &str) -> String {
+        format!(
+            r#"{{
+                "chain_id": "{chain_id}",
+                "chain_name": "{chain_name}",
+                "pretty_name": "{chain_name}",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/{chain_name}.png"}},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"
+            }}"#
+        )
+    }
+
+    fn asset(base: &str, traces: &str) -> String {
+        format!(
+            r#"{{
+                "description": "{base}",
+                "denom_units": [{{"denom": "{base}", "exponent": 0}}],
+                "base": "{base}",
+                "display": "{base}",
+                "name": "{base}",
+                "symbol": "{base}",
+                "traces": [{traces}],
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}}
+            }}"#
+        )
+    }
+
+    fn op_trace(chain_name: &str, base_denom: &str) -> String {
+        format!(
+            r#"{{
+                "type": "op",
+                "counterparty": {{"base_denom": "{base_denom}", "chain_name": "{chain_name}"}},
+                "chain": {{"bridge_id": "11"}}
+            }}"#
+        )
+    }
+
+    fn ibc_trace(chain_name: &str, base_denom: &str) -> String {
+        format!(
+            r#"{{
+                "type": "ibc",
+                "counterparty": {{"chain_name": "{chain_name}", "base_denom": "{base_denom}", "channel_id": "channel-0"}},
+                "chain": {{"channel_id": "channel-25", "path": "transfer/channel-25/{base_denom}"}}
+            }}"#
+        )
+    }
+
+    fn registry(initia_assets: &[String], yominet_assets: &[String]) -> Registry {
+        let chains: ChainList = serde_json::from_str(&format!(
+            "[{}, {}]",
+            chain("interwoven-1", "initia"),
+            chain("yominet-1", "yominet")
+        ))
+        .expect("Failed to deserialize chains");
+        let list = |name: &str, assets: &[String]| -> AssetList {
+            serde_json::from_str(&format!(
+                r#"{{"$schema": "../../assetlist.schema.json", "chain_name": "{name}", "assets": [{}]}}"#,
+                assets.join(",")
+            ))
+            .expect("Failed to deserialize assetlist")
+        };
+        Registry::new(
+            chains,
+            vec![
+                list("initia", initia_assets),
+                list("yominet", yominet_assets),
+            ],
+            ProfileList(vec![]),
+        )
+    }
+
+    #[test]
+    fn test_provenance_to_origin() {
+        let registry = registry(
+            &[
+                asset("uinit", ""),
+                asset(
+                    IBC_INIT_YOMINET,
+                    &format!(
+                        "{}, {}",
+                        op_trace("initia", "uinit"),
+                        ibc_trace("yominet", L2_INIT)
+                    ),
+                ),
+            ],
+            &[asset(L2_INIT, &op_trace("initia", "uinit"))],
+        );
+        let start = registry.asset("initia", IBC_INIT_YOMINET).unwrap();
+
+        let provenance = registry
+            .provenance("initia", start)
+            .expect("Failed to resolve provenance");
+
+        let hops: Vec<_> = provenance
+            .steps
+            .iter()
+            .map(|s| (s.chain_name, s.asset.base.as_str()))
+            .collect();
+        assert_eq!(
+            hops,
+            vec![
+                ("initia", IBC_INIT_YOMINET),
+                ("yominet", L2_INIT),
+                ("initia", "uinit"),
+            ]
+        );
+        assert!(matches!(provenance.steps[0].via, Some(Trace::Ibc { .. })));
+        assert!(matches!(provenance.steps[1].via, Some(Trace::Op { .. })));
+        assert_eq!(provenance.origin().via, None);
+    }
+
+    #[test]
+    fn test_native_asset_is_its_own_origin() {
+        let registry = registry(&[asset("uinit", "")], &[]);
+        let uinit = registry.asset("initia", "uinit").unwrap();
+
+        let provenance = registry.provenance("initia", uinit).unwrap();
+        assert_eq!(provenance.steps.len(), 1);
+        assert_eq!(provenance.origin().asset, uinit);
+    }
+
+    #[test]
+    fn test_missing_hop() {
+        let registry = registry(
+            &[asset("ibc/USDC", &ibc_trace("noble", "uusdc"))],
+            &[asset(L2_INIT, &op_trace("initia", "uinit"))],
+        );
+
+        let usdc = registry.asset("initia", "ibc/USDC").unwrap();
+        assert_eq!(
+            registry.provenance("initia", usdc),
+            Err(ProvenanceError::UnknownChain {
+                chain_name: "noble".to_string()
+            })
+        );
+
+        let l2_init = registry.asset("yominet", L2_INIT).unwrap();
+        assert_eq!(
+            registry.provenance("yominet", l2_init),
+            Err(ProvenanceError::MissingAsset {
+                chain_name: "initia".to_string(),
+                denom: "uinit".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_cycle() {
+        let registry = registry(
+            &[asset("uinit", &ibc_trace("yominet", L2_INIT))],
+            &[asset(L2_INIT, &op_trace("initia", "uinit"))],
+        );
+        let uinit = registry.asset("initia", "uinit").unwrap();
+
+        assert_eq!(
+            registry.provenance("initia", uinit),
+            Err(ProvenanceError::Cycle {
+                chain_name: "initia".to_string(),
+                denom: "uinit".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use crate::{
+    asset::{Asset, AssetList},
+    chain::{Chain, ChainList},
+    profile::{Profile, ProfileList},
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    chains: Vec<Chain>,
+    assetlists: Vec<AssetList>,
+    profiles: Vec<Profile>,
+    chain_by_id: HashMap<String, usize>,
+    chain_by_name: HashMap<String, usize>,
+    assetlist_by_chain: HashMap<String, usize>,
+    // Per-assetlist indexes, parallel to `assetlists`.
+    asset_by_base: Vec<HashMap<String, usize>>,
+    asset_by_denom: Vec<HashMap<String, usize>>,
+    profile_by_name: HashMap<String, usize>,
+}
+
+impl Registry {
+    pub fn new(chains: ChainList, assetlists: Vec<AssetList>, profiles: ProfileList) -> Self {
+        let chains = chains.0;
+        let profiles = profiles.0;
+
+        let chain_by_id = chains
+            .iter()
+            .enumerate()
+            .map(|(i, chain)| (chain.chain_id.clone(), i))
+            .collect();
+        let chain_by_name = chains
+            .iter()
+            .enumerate()
+            .map(|(i, chain)| (chain.chain_name.clone(), i))
+            .collect();
+        let assetlist_by_chain = assetlists
+            .iter()
+            .enumerate()
+            .map(|(i, list)| (list.chain_name.clone(), i))
+            .collect();
+        let asset_by_base = assetlists
+            .iter()
+            .map(|list| {
+                list.assets
+                    .iter()
+                    .enumerate()
+                    .map(|(i, asset)| (asset.base.clone(), i))
+                    .collect()
+            })
+            .collect();
+        let asset_by_denom = assetlists
+            .iter()
+            .map(|list| {
+                let mut index = HashMap::new();
+                for (i, asset) in list.assets.iter().enumerate() {
+                    for unit in &asset.denom_units {
+                        index.entry(unit.denom.clone()).or_insert(i);
+                    }
+                }
+                index
+            })
+            .collect();
+        let profile_by_name = profiles
+            .iter()
+            .enumerate()
+            .map(|(i, profile)| (profile.name.clone(), i))
+            .collect();
+
+        Self {
+            chains,
+            assetlists,
+            profiles,
+            chain_by_id,
+            chain_by_name,
+            assetlist_by_chain,
+            asset_by_base,
+            asset_by_denom,
+            profile_by_name,
+        }
+    }
+
+    pub fn chains(&self) -> &[Chain] {
+        &self.chains
+    }
+
+    pub fn assetlists(&self) -> &[AssetList] {
+        &self.assetlists
+    }
+
+    pub fn profiles(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    pub fn chain_by_id(&self, chain_id: &str) -> Option<&Chain> {
+        self.chain_by_id.get(chain_id).map(|&i| &self.chains[i])
+    }
+
+    pub fn chain_by_name(&self, chain_name: &str) -> Option<&Chain> {
+        self.chain_by_name.get(chain_name).map(|&i| &self.chains[i])
+    }
+
+    pub fn assetlist(&self, chain_name: &str) -> Option<&AssetList> {
+        self.assetlist_by_chain
+            .get(chain_name)
+            .map(|&i| &self.assetlists[i])
+    }
+
+    /// Looks up an asset by its base denom on the given chain.
+    pub fn asset(&self, chain_name: &str, base: &str) -> Option<&Asset> {
+        let list = *self.assetlist_by_chain.get(chain_name)?;
+        let asset = *self.asset_by_base[list].get(base)?;
+        Some(&self.assetlists[list].assets[asset])
+    }
+
+    /// Looks up an asset on the given chain by any of its `denom_units`, e.g. `uinit` or `INIT`.
+    pub fn asset_by_denom(&self, chain_name: &str, denom: &str) -> Option<&Asset> {
+        let list = *self.assetlist_by_chain.get(chain_name)?;
+        let asset = *self.asset_by_denom[list].get(denom)?;
+        Some(&self.assetlists[list].assets[asset])
+    }
+
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profile_by_name.get(name).map(|&i| &self.profiles[i])
+    }
+
+    pub fn assetlist_for_chain(&self, chain: &Chain) -> Option<&AssetList> {
+        self.assetlist(&chain.chain_name)
+    }
+
+    pub fn profile_for_chain(&self, chain: &Chain) -> Option<&Profile> {
+        self.profile(&chain.chain_name)
+    }
+
+    pub fn chain_for_assetlist(&self, assetlist: &AssetList) -> Option<&Chain> {
+        self.chain_by_name(&assetlist.chain_name)
+    }
+
+    /// Returns the L1 of the chain's network, i.e. the chain marked `is_l1` with the same
+    /// `network_type`.
+    pub fn l1_for(&self, chain: &Chain) -> Option<&Chain> {
+        self.chains
+            .iter()
+            .find(|c| c.metadata.is_l1 == Some(true) && c.network_type == chain.network_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_registry() -> Registry {
+        let chains: ChainList = serde_json::from_str(
+            r#"[
+                {
+                    "chain_id": "interwoven-1",
+                    "chain_name": "initia",
+                    "pretty_name": "Initia",
+                    "fees": {"fee_tokens": [{"denom": "uinit"}]},
+                    "apis": {},
+                    "explorers": [],
+                    "metadata": {"is_l1": true},
+                    "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"},
+                    "slip44": 60,
+                    "bech32_prefix": "init",
+                    "network_type": "mainnet"
+                },
+                {
+                    "chain_id": "yominet-1",
+                    "chain_name": "yominet",
+                    "pretty_name": "Yominet",
+                    "fees": {"fee_tokens": []},
+                    "apis": {},
+                    "explorers": [],
+                    "metadata": {"op_bridge_id": "11", "op_denoms": ["uinit"]},
+                    "logo_URIs": {"png": "https://registry.initia.xyz/images/yominet.png"},
+                    "slip44": 60,
+                    "bech32_prefix": "init",
+                    "network_type": "mainnet"
+                }
+            ]"#,
+        )
+        .expect("Failed to deserialize chain list");
+
+        let assetlists: Vec<AssetList> = vec![
+            serde_json::from_str(
+                r#"{
+                    "$schema": "../../assetlist.schema.json",
+                    "chain_name": "initia",
+                    "assets": [
+                        {
+                            "description": "The native token of Initia",
+                            "denom_units": [
+                                {"denom": "uinit", "exponent": 0},
+                                {"denom": "INIT", "exponent": 6}
+                            ],
+                            "base": "uinit",
+                            "display": "INIT",
+                            "name": "Initia Native Token",
+                            "symbol": "INIT",
+                            "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+                        }
+                    ]
+                }"#,
+            )
+            .expect("Failed to deserialize asset list"),
+        ];
+
+        let profiles: ProfileList = serde_json::from_str(
+            r##"[
+                {
+                    "name": "yominet",
+                    "pretty_name": "Yominet",
+                    "category": "Gaming",
+                    "l2": true,
+                    "description": "Home to the Kamigotchi.",
+                    "logo": "https://registry.initia.xyz/images/yominet.png",
+                    "color": "#000000",
+                    "status": "live",
+                    "social": {
+                        "website": "https://kamigotchi.io",
+                        "twitter": "https://x.com/kamigotchiworld"
+                    }
+                }
+            ]"##,
+        )
+        .expect("Failed to deserialize profile list");
+
+        Registry::new(chains, assetlists, profiles)
+    }
+
+    #[test]
+    fn test_chain_lookups() {
+        let registry = sample_registry();
+
+        assert_eq!(registry.chains().len(), 2);
+        assert_eq!(
+            registry
+                .chain_by_id("interwoven-1")
+                .map(|c| c.chain_name.as_str()),
+            Some("initia")
+        );
+        assert_eq!(
+            registry
+                .chain_by_name("yominet")
+                .map(|c| c.chain_id.as_str()),
+            Some("yominet-1")
+        );
+        assert!(registry.chain_by_id("unknown-1").is_none());
+        assert!(registry.chain_by_name("unknown").is_none());
+    }
+
+    #[test]
+    fn test_asset_lookups() {
+        let registry = sample_registry();
+
+        let init = registry.asset("initia", "uinit").expect("Expected INIT");
+        assert_eq!(init.symbol, "INIT");
+        assert!(registry.asset("initia", "INIT").is_none());
+        assert!(registry.asset("yominet", "uinit").is_none());
+
+        let by_display = registry
+            .asset_by_denom("initia", "INIT")
+            .expect("Expected INIT by display denom");
+        assert_eq!(by_display, init);
+        assert_eq!(registry.asset_by_denom("initia", "uinit"), Some(init));
+    }
+
+    #[test]
+    fn test_joins() {
+        let registry = sample_registry();
+
+        let initia = registry.chain_by_name("initia").unwrap();
+        let yominet = registry.chain_by_name("yominet").unwrap();
+
+        let assetlist = registry
+            .assetlist_for_chain(initia)
+            .expect("Expected initia assetlist");
+        assert_eq!(assetlist.chain_name, "initia");
+        assert_eq!(registry.chain_for_assetlist(assetlist), Some(initia));
+        assert!(registry.assetlist_for_chain(yominet).is_none());
+
+        let profile = registry
+            .profile_for_chain(yominet)
+            .expect("Expected yominet profile");
+        assert_eq!(profile.category, "Gaming");
+        assert!(registry.profile_for_chain(initia).is_none());
+        assert_eq!(registry.profile("yominet"), Some(profile));
+    }
+}
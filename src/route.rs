@@ -0,0 +1,440 @@
+use crate::{
+    asset::Trace,
+    chain::Chain,
+    denom::{Denom, IBC_PREFIX},
+    ibc_graph::{IbcGraph, TRANSFER_PORT},
+    registry::Registry,
+};
+
+pub const DEFAULT_MAX_HOPS: usize = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HopKind {
+    /// OPinit deposit from the L1 into an L2.
+    OpDeposit { bridge_id: u64 },
+    /// OPinit withdrawal from an L2 back to its L1.
+    OpWithdraw { bridge_id: u64 },
+    /// ICS-20 transfer, sent over `channel_id` and received on `counterparty_channel_id`.
+    Ibc {
+        port_id: String,
+        channel_id: String,
+        counterparty_channel_id: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hop {
+    pub kind: HopKind,
+    pub from_chain: String,
+    pub to_chain: String,
+    /// The denom the user holds on `to_chain` after the hop.
+    pub denom: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Route {
+    pub hops: Vec<Hop>,
+}
+
+impl Route {
+    pub fn len(&self) -> usize {
+        self.hops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hops.is_empty()
+    }
+
+    pub fn final_denom(&self) -> Option<&str> {
+        self.hops.last().map(|h| h.denom.as_str())
+    }
+}
+
+/// A position in the search: a denom held on a chain, plus its full denom trace path when it
+/// is an IBC voucher.
+#[derive(Clone)]
+struct State<'a> {
+    chain: &'a Chain,
+    denom: String,
+    ibc_path: Option<String>,
+}
+
+/// Enumerates transfer routes over OPinit bridges and `transfer` channels of a registry.
+#[derive(Clone, Debug)]
+pub struct RoutePlanner<'a> {
+    registry: &'a Registry,
+    graph: IbcGraph<'a>,
+    max_hops: usize,
+}
+
+impl<'a> RoutePlanner<'a> {
+    pub fn new(registry: &'a Registry) -> Self {
+        Self {
+            registry,
+            graph: IbcGraph::for_port(registry, TRANSFER_PORT, None),
+            max_hops: DEFAULT_MAX_HOPS,
+        }
+    }
+
+    pub fn max_hops(mut self, max_hops: usize) -> Self {
+        self.max_hops = max_hops;
+        self
+    }
+
+    /// Lists routes moving `from_denom` on `from_chain` to `to_chain`, ending in `to_denom`
+    /// when given. Routes never revisit a `(chain, denom)` pair and are ordered by hop count.
+    pub fn routes(
+        &self,
+        from_chain: &str,
+        from_denom: &str,
+        to_chain: &str,
+        to_denom: Option<&str>,
+    ) -> Vec<Route> {
+        let Some(chain) = self.registry.chain_by_name(from_chain) else {
+            return vec![];
+        };
+        let start = State {
+            chain,
+            denom: from_denom.to_string(),
+            ibc_path: self.ibc_path(from_chain, from_denom),
+        };
+
+        let mut out = Vec::new();
+        let mut visited = vec![(chain.chain_name.clone(), from_denom.to_string())];
+        let mut hops = Vec::new();
+        let is_target = |state: &State| {
+            state.chain.chain_name == to_chain && to_denom.is_none_or(|d| state.denom == d)
+        };
+        self.search(&start, &is_target, &mut visited, &mut hops, &mut out);
+        out.sort_by_key(Route::len);
+        out
+    }
+
+    fn search(
+        &self,
+        state: &State<'a>,
+        is_target: &dyn Fn(&State) -> bool,
+        visited: &mut Vec<(String, String)>,
+        hops: &mut Vec<Hop>,
+        out: &mut Vec<Route>,
+    ) {
+        if hops.len() == self.max_hops {
+            return;
+        }
+        for (hop, next) in self.next_states(state) {
+            let key = (next.chain.chain_name.clone(), next.denom.clone());
+            if visited.contains(&key) {
+                continue;
+            }
+            hops.push(hop);
+            if is_target(&next) {
+                out.push(Route { hops: hops.clone() });
+            } else {
+                visited.push(key);
+                self.search(&next, is_target, visited, hops, out);
+                visited.pop();
+            }
+            hops.pop();
+        }
+    }
+
+    fn next_states(&self, state: &State<'a>) -> Vec<(Hop, State<'a>)> {
+        let mut out = Vec::new();
+        let hop = |kind, to: &Chain, denom: &str| Hop {
+            kind,
+            from_chain: state.chain.chain_name.clone(),
+            to_chain: to.chain_name.clone(),
+            denom: denom.to_string(),
+        };
+
+        for l2 in self.registry.chains() {
+            let Some(bridge_id) = bridge_id(l2) else {
+                continue;
+            };
+            let is_l1 = self
+                .registry
+                .l1_for(l2)
+                .is_some_and(|l1| l1.chain_id == state.chain.chain_id);
+            if is_l1 && l2.metadata.op_denoms.contains(&state.denom) {
+                let denom = Denom::l2_from_bridge(bridge_id, &state.denom).to_string();
+                out.push((
+                    hop(HopKind::OpDeposit { bridge_id }, l2, &denom),
+                    State {
+                        chain: l2,
+                        denom,
+                        ibc_path: None,
+                    },
+                ));
+            }
+        }
+
+        if let Some(bridge_id) = bridge_id(state.chain)
+            && let Some(l1) = self.registry.l1_for(state.chain)
+        {
+            for l1_denom in &state.chain.metadata.op_denoms {
+                if Denom::l2_from_bridge(bridge_id, l1_denom).to_string() == state.denom {
+                    out.push((
+                        hop(HopKind::OpWithdraw { bridge_id }, l1, l1_denom),
+                        State {
+                            chain: l1,
+                            denom: l1_denom.clone(),
+                            ibc_path: self.ibc_path(&l1.chain_name, l1_denom),
+                        },
+                    ));
+                }
+            }
+        }
+
+        // Vouchers whose trace path is unknown cannot be forwarded or unwound.
+        if state.denom.starts_with(IBC_PREFIX) && state.ibc_path.is_none() {
+            return out;
+        }
+        let full_path = state.ibc_path.as_deref().unwrap_or(&state.denom);
+        for counterparty_id in self.graph.neighbors(&state.chain.chain_id) {
+            let Some(counterparty) = self.registry.chain_by_id(counterparty_id) else {
+                continue;
+            };
+            let Some(pair) = self
+                .graph
+                .channel_pair(&state.chain.chain_id, counterparty_id)
+            else {
+                continue;
+            };
+            let channel = &pair.source.channel;
+            let counterparty_channel = &pair.destination.channel;
+
+            let unwound = full_path
+                .strip_prefix(&format!("{}/{}/", channel.port_id, channel.channel_id))
+                .map(str::to_string);
+            let (denom, ibc_path) = match unwound {
+                Some(rest) if is_trace_path(&rest) => {
+                    (Denom::ibc_from_path(&rest).to_string(), Some(rest))
+                }
+                Some(rest) => (rest, None),
+                None => {
+                    let path = format!(
+                        "{}/{}/{full_path}",
+                        counterparty_channel.port_id, counterparty_channel.channel_id
+                    );
+                    (Denom::ibc_from_path(&path).to_string(), Some(path))
+                }
+            };
+            out.push((
+                hop(
+                    HopKind::Ibc {
+                        port_id: channel.port_id.clone(),
+                        channel_id: channel.channel_id.clone(),
+                        counterparty_channel_id: counterparty_channel.channel_id.clone(),
+                    },
+                    counterparty,
+                    &denom,
+                ),
+                State {
+                    chain: counterparty,
+                    denom,
+                    ibc_path,
+                },
+            ));
+        }
+        out
+    }
+
+    /// The full denom trace path of a registered IBC voucher.
+    fn ibc_path(&self, chain_name: &str, denom: &str) -> Option<String> {
+        if !denom.starts_with(IBC_PREFIX) {
+            return None;
+        }
+        match self.registry.asset(chain_name, denom)?.traces.last()? {
+            Trace::Ibc { chain, .. } => Some(chain.path.clone()),
+            _ => None,
+        }
+    }
+}
+
+fn bridge_id(chain: &Chain) -> Option<u64> {
+    if chain.metadata.is_l1 == Some(true) {
+        return None;
+    }
+    chain.metadata.op_bridge_id.as_ref()?.parse().ok()
+}
+
+/// Whether `path` still starts with a `port/channel-N/` hop rather than being a base denom
+/// that itself contains slashes, such as `l2/...`.
+fn is_trace_path(path: &str) -> bool {
+    let mut segments = path.splitn(3, '/');
+    segments.next().is_some()
+        && segments.next().is_some_and(|s| s.starts_with("channel-"))
+        && segments.next().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{asset::AssetList, chain::ChainList, profile::ProfileList};
+
+    fn chain(
+        chain_id: &str,
+        chain_name: &str,
+        metadata: &str,
+        channels: &[(&str, &str)],
+    ) -> String {
+        let channels: Vec<_> = channels
+            .iter()
+            .map(|(counterparty, channel)| {
+                format!(
+                    r#"{{"chain_id": "{counterparty}", "port_id": "transfer", "channel_id": "{channel}", "version": "ics20-1"}}"#
+                )
+            })
+            .collect();
+        format!(
+            r#"{{
+                "chain_id": "{chain_id}",
+                "chain_name": "{chain_name}",
+                "pretty_name": "{chain_name}",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{{metadata} "ibc_channels": [{}]}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"
+            }}"#,
+            channels.join(",")
+        )
+    }
+
+    fn registry() -> Registry {
+        let chains = [
+            chain(
+                "interwoven-1",
+                "initia",
+                r#""is_l1": true,"#,
+                &[("yominet-1", "channel-25"), ("echelon-1", "channel-30")],
+            ),
+            chain(
+                "yominet-1",
+                "yominet",
+                r#""op_bridge_id": "11", "op_denoms": ["uinit"],"#,
+                &[("interwoven-1", "channel-0")],
+            ),
+            chain(
+                "echelon-1",
+                "echelon",
+                r#""op_bridge_id": "30", "op_denoms": ["uinit"],"#,
+                &[("interwoven-1", "channel-0")],
+            ),
+        ];
+        let chains: ChainList = serde_json::from_str(&format!("[{}]", chains.join(",")))
+            .expect("Failed to deserialize chains");
+        let yominet: AssetList = serde_json::from_str(&format!(
+            r#"{{
+                "$schema": "../../assetlist.schema.json",
+                "chain_name": "yominet",
+                "assets": [
+                    {{
+                        "description": "INIT over IBC",
+                        "denom_units": [{{"denom": "{0}", "exponent": 0}}],
+                        "base": "{0}",
+                        "display": "{0}",
+                        "name": "Initia Native Token",
+                        "symbol": "INIT",
+                        "traces": [
+                            {{
+                                "type": "ibc",
+                                "counterparty": {{"chain_name": "initia", "base_denom": "uinit", "channel_id": "channel-25"}},
+                                "chain": {{"channel_id": "channel-0", "path": "transfer/channel-0/uinit"}}
+                            }}
+                        ],
+                        "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}}
+                    }}
+                ]
+            }}"#,
+            Denom::ibc_from_path("transfer/channel-0/uinit")
+        ))
+        .expect("Failed to deserialize assetlist");
+        Registry::new(chains, vec![yominet], ProfileList(vec![]))
+    }
+
+    #[test]
+    fn test_route_between_rollups() {
+        let registry = registry();
+        let planner = RoutePlanner::new(&registry);
+        let yominet_init = Denom::l2_from_bridge(11, "uinit").to_string();
+        let echelon_init = Denom::l2_from_bridge(30, "uinit").to_string();
+
+        let routes = planner.routes("yominet", &yominet_init, "echelon", Some(&echelon_init));
+        assert_eq!(
+            routes,
+            vec![Route {
+                hops: vec![
+                    Hop {
+                        kind: HopKind::OpWithdraw { bridge_id: 11 },
+                        from_chain: "yominet".to_string(),
+                        to_chain: "initia".to_string(),
+                        denom: "uinit".to_string(),
+                    },
+                    Hop {
+                        kind: HopKind::OpDeposit { bridge_id: 30 },
+                        from_chain: "initia".to_string(),
+                        to_chain: "echelon".to_string(),
+                        denom: echelon_init.clone(),
+                    },
+                ]
+            }]
+        );
+
+        let any = planner.routes("yominet", &yominet_init, "echelon", None);
+        assert!(any.iter().all(|r| r.len() <= DEFAULT_MAX_HOPS));
+        assert_eq!(any[0].len(), 2);
+        let ibc_only = any
+            .iter()
+            .find(|r| r.hops.iter().all(|h| matches!(h.kind, HopKind::Ibc { .. })))
+            .expect("Failed to find IBC route");
+        assert_eq!(
+            ibc_only.final_denom(),
+            Some(
+                Denom::ibc_from_path(&format!(
+                    "transfer/channel-0/transfer/channel-25/{yominet_init}"
+                ))
+                .to_string()
+                .as_str()
+            )
+        );
+
+        assert!(
+            planner
+                .clone()
+                .max_hops(1)
+                .routes("yominet", &yominet_init, "echelon", None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_route_unwinds_ibc_voucher() {
+        let registry = registry();
+        let voucher = Denom::ibc_from_path("transfer/channel-0/uinit").to_string();
+
+        let routes = RoutePlanner::new(&registry).routes("yominet", &voucher, "initia", None);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(
+            routes[0].hops[0].kind,
+            HopKind::Ibc {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+                counterparty_channel_id: "channel-25".to_string(),
+            }
+        );
+        assert_eq!(routes[0].final_denom(), Some("uinit"));
+    }
+
+    #[test]
+    fn test_trace_path_detection() {
+        assert!(is_trace_path("transfer/channel-3/uusdc"));
+        assert!(!is_trace_path("uusdc"));
+        assert!(!is_trace_path(
+            "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06"
+        ));
+    }
+}
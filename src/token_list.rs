@@ -0,0 +1,504 @@
+use std::{collections::HashSet, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    address::{decode_hex, encode_hex},
+    asset::{Asset, AssetList},
+    denom::EVM_PREFIX,
+    registry::Registry,
+    validate::{Diagnostic, Validate},
+};
+
+pub const MAX_TOKENS: usize = 10_000;
+
+/// Rule ids of the token list schema checks, kept apart from the registry rules in
+/// [`crate::validate::rules`].
+pub mod rules {
+    pub const TOKENLIST_NAME: &str = "tokenlist.name";
+    pub const TOKENLIST_TIMESTAMP: &str = "tokenlist.timestamp";
+    pub const TOKENLIST_TOKEN_COUNT: &str = "tokenlist.token-count";
+    pub const TOKENLIST_DUPLICATE_TOKEN: &str = "tokenlist.duplicate-token";
+    pub const TOKENLIST_TOKEN_CHAIN_ID: &str = "tokenlist.token-chain-id";
+    pub const TOKENLIST_TOKEN_ADDRESS: &str = "tokenlist.token-address";
+    pub const TOKENLIST_TOKEN_NAME: &str = "tokenlist.token-name";
+    pub const TOKENLIST_TOKEN_SYMBOL: &str = "tokenlist.token-symbol";
+    pub const TOKENLIST_TOKEN_LOGO_URI: &str = "tokenlist.token-logo-uri";
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenListError {
+    InvalidAddress { chain_name: String, denom: String },
+    MissingDecimals { chain_name: String, denom: String },
+    InvalidDecimals { denom: String, decimals: u32 },
+}
+
+impl fmt::Display for TokenListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenListError::InvalidAddress { chain_name, denom } => {
+                write!(f, "`{denom}` on `{chain_name}` is not a valid `evm/` denom")
+            }
+            TokenListError::MissingDecimals { chain_name, denom } => {
+                write!(f, "`{denom}` on `{chain_name}` has no display unit")
+            }
+            TokenListError::InvalidDecimals { denom, decimals } => {
+                write!(
+                    f,
+                    "`{denom}` has {decimals} decimals, more than fit in a u8"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokenListError {}
+
+/// A Uniswap `tokenlist.json`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TokenList {
+    pub name: String,
+    /// RFC 3339 date-time of this version of the list.
+    pub timestamp: String,
+    pub version: Version,
+    pub tokens: Vec<TokenInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    pub chain_id: u64,
+    /// EIP-55 checksummed `0x` address.
+    pub address: String,
+    pub decimals: u8,
+    pub symbol: String,
+    pub name: String,
+    #[serde(rename = "logoURI", skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+}
+
+impl TokenInfo {
+    /// Converts an `evm/<address>` asset, leaving out a logo that is not a URI. Returns
+    /// `Ok(None)` for any other kind of denom.
+    pub fn from_asset(
+        chain_name: &str,
+        chain_id: u64,
+        asset: &Asset,
+    ) -> Result<Option<Self>, TokenListError> {
+        let Some(contract) = asset.base.strip_prefix(EVM_PREFIX) else {
+            return Ok(None);
+        };
+        let address =
+            decode_hex(&format!("0x{contract}")).map_err(|_| TokenListError::InvalidAddress {
+                chain_name: chain_name.to_string(),
+                denom: asset.base.clone(),
+            })?;
+        let decimals = asset
+            .decimals()
+            .ok_or_else(|| TokenListError::MissingDecimals {
+                chain_name: chain_name.to_string(),
+                denom: asset.base.clone(),
+            })?;
+
+        Ok(Some(Self {
+            chain_id,
+            address: encode_hex(&address),
+            decimals: decimals
+                .try_into()
+                .map_err(|_| TokenListError::InvalidDecimals {
+                    denom: asset.base.clone(),
+                    decimals,
+                })?,
+            symbol: asset.symbol.clone(),
+            name: asset.name.clone(),
+            logo_uri: Some(asset.logo_uris.url())
+                .filter(|url| is_uri(url))
+                .map(str::to_string),
+        }))
+    }
+
+    /// Every `evm/` asset of an assetlist, in assetlist order.
+    pub fn from_assetlist(
+        chain_id: u64,
+        assetlist: &AssetList,
+    ) -> Result<Vec<Self>, TokenListError> {
+        let mut tokens = Vec::new();
+        for asset in &assetlist.assets {
+            tokens.extend(Self::from_asset(&assetlist.chain_name, chain_id, asset)?);
+        }
+        Ok(tokens)
+    }
+}
+
+impl Registry {
+    /// Collects the `evm/` assets of every chain with an `evm_chain_id` into one token list.
+    pub fn token_list(
+        &self,
+        name: impl Into<String>,
+        timestamp: impl Into<String>,
+        version: Version,
+    ) -> Result<TokenList, TokenListError> {
+        let mut tokens = Vec::new();
+        for assetlist in self.assetlists() {
+            let Some(chain_id) = self
+                .chain_for_assetlist(assetlist)
+                .and_then(|c| c.evm_chain_id)
+            else {
+                continue;
+            };
+            tokens.extend(TokenInfo::from_assetlist(chain_id, assetlist)?);
+        }
+        Ok(TokenList {
+            name: name.into(),
+            timestamp: timestamp.into(),
+            version,
+            tokens,
+        })
+    }
+}
+
+/// Checks the constraints of the token list JSON schema: lengths and patterns of names and
+/// symbols, address and timestamp formats, token count and uniqueness.
+impl Validate for TokenList {
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+
+        let len = self.name.chars().count();
+        if !(1..=30).contains(&len)
+            || !self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ' ')
+        {
+            out.push(Diagnostic::error(
+                rules::TOKENLIST_NAME,
+                "$.name",
+                "name must be 1 to 30 word characters or spaces",
+            ));
+        }
+        if !is_date_time(&self.timestamp) {
+            out.push(Diagnostic::error(
+                rules::TOKENLIST_TIMESTAMP,
+                "$.timestamp",
+                format!("`{}` is not an RFC 3339 date-time", self.timestamp),
+            ));
+        }
+        if !(1..=MAX_TOKENS).contains(&self.tokens.len()) {
+            out.push(Diagnostic::error(
+                rules::TOKENLIST_TOKEN_COUNT,
+                "$.tokens",
+                format!(
+                    "list has {} tokens, expected 1 to {MAX_TOKENS}",
+                    self.tokens.len()
+                ),
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        for (i, token) in self.tokens.iter().enumerate() {
+            let path = format!("$.tokens[{i}]");
+            if !seen.insert((token.chain_id, token.address.to_lowercase())) {
+                out.push(Diagnostic::error(
+                    rules::TOKENLIST_DUPLICATE_TOKEN,
+                    &path,
+                    format!(
+                        "`{}` on chain {} is listed more than once",
+                        token.address, token.chain_id
+                    ),
+                ));
+            }
+            validate_token(token, &path, &mut out);
+        }
+        out
+    }
+}
+
+fn validate_token(token: &TokenInfo, path: &str, out: &mut Vec<Diagnostic>) {
+    if token.chain_id == 0 {
+        out.push(Diagnostic::error(
+            rules::TOKENLIST_TOKEN_CHAIN_ID,
+            format!("{path}.chainId"),
+            "chainId must be at least 1",
+        ));
+    }
+    let digits = token.address.strip_prefix("0x").unwrap_or_default();
+    if digits.len() != 40 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        out.push(Diagnostic::error(
+            rules::TOKENLIST_TOKEN_ADDRESS,
+            format!("{path}.address"),
+            format!("`{}` is not a 0x address", token.address),
+        ));
+    }
+    let len = token.name.chars().count();
+    if !(1..=60).contains(&len) || token.name.chars().any(|c| c.is_whitespace() && c != ' ') {
+        out.push(Diagnostic::error(
+            rules::TOKENLIST_TOKEN_NAME,
+            format!("{path}.name"),
+            format!("`{}` must be 1 to 60 characters on one line", token.name),
+        ));
+    }
+    let len = token.symbol.chars().count();
+    if !(1..=20).contains(&len) || token.symbol.chars().any(char::is_whitespace) {
+        out.push(Diagnostic::error(
+            rules::TOKENLIST_TOKEN_SYMBOL,
+            format!("{path}.symbol"),
+            format!(
+                "`{}` must be 1 to 20 characters without whitespace",
+                token.symbol
+            ),
+        ));
+    }
+    if let Some(uri) = &token.logo_uri
+        && !is_uri(uri)
+    {
+        out.push(Diagnostic::error(
+            rules::TOKENLIST_TOKEN_LOGO_URI,
+            format!("{path}.logoURI"),
+            format!("`{uri}` is not a URI"),
+        ));
+    }
+}
+
+/// `YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)`.
+fn is_date_time(s: &str) -> bool {
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let b = s.as_bytes();
+    if !s.is_ascii()
+        || b.len() < 20
+        || !digits(&s[0..4])
+        || b[4] != b'-'
+        || !digits(&s[5..7])
+        || b[7] != b'-'
+        || !digits(&s[8..10])
+        || !matches!(b[10], b'T' | b't')
+        || !digits(&s[11..13])
+        || b[13] != b':'
+        || !digits(&s[14..16])
+        || b[16] != b':'
+        || !digits(&s[17..19])
+    {
+        return false;
+    }
+    let mut rest = &s[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let end = fraction
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(fraction.len());
+        if end == 0 {
+            return false;
+        }
+        rest = &fraction[end..];
+    }
+    match rest.as_bytes() {
+        [b'Z' | b'z'] => true,
+        [b'+' | b'-', _, _, b':', _, _] => digits(&rest[1..3]) && digits(&rest[4..6]),
+        _ => false,
+    }
+}
+
+fn is_uri(s: &str) -> bool {
+    let Some((scheme, rest)) = s.split_once(':') else {
+        return false;
+    };
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !rest.is_empty()
+        && !rest.contains(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chain::ChainList, common::ImageType, profile::ProfileList};
+
+    const CONTRACT: &str = "e1ff7038eaaaf027031688e1535a055b2bac2546";
+    const CHECKSUMMED: &str = "0xE1Ff7038eAAAF027031688E1535a055B2Bac2546";
+
+    fn chain(chain_name: &str, evm_chain_id: Option<u64>) -> String {
+        let evm_chain_id = evm_chain_id
+            .map(|id| format!(r#", "evm_chain_id": {id}"#))
+            .unwrap_or_default();
+        format!(
+            r#"{{
+                "chain_id": "{chain_name}-1",
+                "chain_name": "{chain_name}",
+                "pretty_name": "{chain_name}",
+                "fees": {{"fee_tokens": []}},
+                "apis": {{}},
+                "explorers": [],
+                "metadata": {{}},
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/INIT.png"}},
+                "slip44": 60,
+                "bech32_prefix": "init",
+                "network_type": "mainnet"{evm_chain_id}
+            }}"#
+        )
+    }
+
+    fn asset(base: &str, symbol: &str, exponent: u32) -> String {
+        format!(
+            r#"{{
+                "description": "{symbol}",
+                "denom_units": [
+                    {{"denom": "{base}", "exponent": 0}},
+                    {{"denom": "{symbol}", "exponent": {exponent}}}
+                ],
+                "base": "{base}",
+                "display": "{symbol}",
+                "name": "{symbol} Token",
+                "symbol": "{symbol}",
+                "logo_URIs": {{"png": "https://registry.initia.xyz/images/{symbol}.png"}}
+            }}"#
+        )
+    }
+
+    fn assetlist(chain_name: &str, assets: &[String]) -> AssetList {
+        serde_json::from_str(&format!(
+            r#"{{
+                "$schema": "../../assetlist.schema.json",
+                "chain_name": "{chain_name}",
+                "assets": [{}]
+            }}"#,
+            assets.join(",")
+        ))
+        .expect("Failed to deserialize assetlist")
+    }
+
+    fn registry() -> Registry {
+        let chains: ChainList = serde_json::from_str(&format!(
+            "[{}, {}]",
+            chain("yominet", Some(428962654539583)),
+            chain("initia", None)
+        ))
+        .expect("Failed to deserialize chains");
+        let assetlists = vec![
+            assetlist(
+                "yominet",
+                &[
+                    asset(&format!("evm/{CONTRACT}"), "USDC", 6),
+                    asset("uinit", "INIT", 6),
+                ],
+            ),
+            assetlist("initia", &[asset(&format!("evm/{CONTRACT}"), "ETH", 18)]),
+        ];
+        Registry::new(chains, assetlists, ProfileList(vec![]))
+    }
+
+    #[test]
+    fn test_token_list() {
+        let list = registry()
+            .token_list(
+                "Initia EVM",
+                "2026-10-17T00:00:00Z",
+                Version {
+                    major: 1,
+                    minor: 2,
+                    patch: 0,
+                },
+            )
+            .expect("Failed to build token list");
+
+        assert_eq!(
+            serde_json::to_value(&list).unwrap(),
+            serde_json::json!({
+                "name": "Initia EVM",
+                "timestamp": "2026-10-17T00:00:00Z",
+                "version": {"major": 1, "minor": 2, "patch": 0},
+                "tokens": [{
+                    "chainId": 428962654539583u64,
+                    "address": CHECKSUMMED,
+                    "decimals": 6,
+                    "symbol": "USDC",
+                    "name": "USDC Token",
+                    "logoURI": "https://registry.initia.xyz/images/USDC.png"
+                }]
+            })
+        );
+        assert!(list.validate().is_empty());
+    }
+
+    #[test]
+    fn test_token_list_errors() {
+        let list = assetlist("yominet", &[asset("evm/0x1234", "BAD", 6)]);
+        assert_eq!(
+            TokenInfo::from_assetlist(1, &list),
+            Err(TokenListError::InvalidAddress {
+                chain_name: "yominet".to_string(),
+                denom: "evm/0x1234".to_string()
+            })
+        );
+
+        let list = assetlist("yominet", &[asset(&format!("evm/{CONTRACT}"), "BIG", 300)]);
+        assert!(matches!(
+            TokenInfo::from_assetlist(1, &list),
+            Err(TokenListError::InvalidDecimals { decimals: 300, .. })
+        ));
+    }
+
+    #[test]
+    fn test_token_info_skips_invalid_logo() {
+        let mut asset: Asset =
+            serde_json::from_str(&asset(&format!("evm/{CONTRACT}"), "USDC", 6)).unwrap();
+        asset.logo_uris = ImageType::PNG("images/USDC.png".to_string());
+
+        let token = TokenInfo::from_asset("yominet", 1, &asset)
+            .unwrap()
+            .expect("Failed to convert evm asset");
+        assert_eq!(token.logo_uri, None);
+    }
+
+    #[test]
+    fn test_validate_token_list() {
+        let token = TokenInfo {
+            chain_id: 0,
+            address: "0x1234".to_string(),
+            decimals: 6,
+            symbol: "US DC".to_string(),
+            name: "USD\nCoin".to_string(),
+            logo_uri: Some("not a uri".to_string()),
+        };
+        let list = TokenList {
+            name: "Initia EVM!".to_string(),
+            timestamp: "2026-10-17".to_string(),
+            version: Version::default(),
+            tokens: vec![token.clone(), token],
+        };
+
+        let found: Vec<_> = list.validate().into_iter().map(|d| d.rule).collect();
+        assert_eq!(
+            found,
+            vec![
+                rules::TOKENLIST_NAME,
+                rules::TOKENLIST_TIMESTAMP,
+                rules::TOKENLIST_TOKEN_CHAIN_ID,
+                rules::TOKENLIST_TOKEN_ADDRESS,
+                rules::TOKENLIST_TOKEN_NAME,
+                rules::TOKENLIST_TOKEN_SYMBOL,
+                rules::TOKENLIST_TOKEN_LOGO_URI,
+                rules::TOKENLIST_DUPLICATE_TOKEN,
+                rules::TOKENLIST_TOKEN_CHAIN_ID,
+                rules::TOKENLIST_TOKEN_ADDRESS,
+                rules::TOKENLIST_TOKEN_NAME,
+                rules::TOKENLIST_TOKEN_SYMBOL,
+                rules::TOKENLIST_TOKEN_LOGO_URI,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_date_time() {
+        assert!(is_date_time("2026-10-17T12:30:00Z"));
+        assert!(is_date_time("2026-10-17T12:30:00.123+09:00"));
+        assert!(!is_date_time("2026-10-17 12:30:00"));
+        assert!(!is_date_time("2026-10-17T12:30:00"));
+    }
+}
@@ -0,0 +1,759 @@
+use std::{collections::HashSet, fmt};
+
+use serde::Serialize;
+
+use crate::{
+    asset::{Asset, AssetList, Trace},
+    chain::Chain,
+    denom::Denom,
+    profile::Profile,
+    registry::Registry,
+};
+
+pub mod rules {
+    pub const ASSET_BASE_UNIT: &str = "asset.base-unit";
+    pub const ASSET_DISPLAY_UNIT: &str = "asset.display-unit";
+    pub const ASSET_DUPLICATE_UNIT: &str = "asset.duplicate-unit";
+    pub const ASSET_UNIT_ORDER: &str = "asset.unit-order";
+    pub const ASSET_IBC_HASH: &str = "asset.ibc-hash";
+    pub const ASSET_IBC_PATH: &str = "asset.ibc-path";
+    pub const ASSET_OP_DENOM: &str = "asset.op-denom";
+    pub const ASSETLIST_DUPLICATE_BASE: &str = "assetlist.duplicate-base";
+    pub const CHAIN_EXPLORER_TX_PAGE: &str = "chain.explorer-tx-page";
+    pub const CHAIN_EXPLORER_ACCOUNT_PAGE: &str = "chain.explorer-account-page";
+    pub const CHAIN_GAS_PRICE: &str = "chain.gas-price";
+    pub const CHAIN_GAS_PRICE_ORDER: &str = "chain.gas-price-order";
+    pub const CHAIN_OP_BRIDGE_ID: &str = "chain.op-bridge-id";
+    pub const CHAIN_OP_FEE_TOKEN: &str = "chain.op-fee-token";
+    pub const PROFILE_COLOR: &str = "profile.color";
+    pub const REGISTRY_UNKNOWN_ASSETLIST_CHAIN: &str = "registry.unknown-assetlist-chain";
+    pub const REGISTRY_UNKNOWN_TRACE_CHAIN: &str = "registry.unknown-trace-chain";
+    pub const REGISTRY_UNKNOWN_IBC_CHAIN: &str = "registry.unknown-ibc-chain";
+    pub const REGISTRY_MISSING_REVERSE_CHANNEL: &str = "registry.missing-reverse-channel";
+    pub const REGISTRY_MISSING_ASSETLIST: &str = "registry.missing-assetlist";
+    pub const REGISTRY_FEE_TOKEN_NOT_IN_ASSETLIST: &str = "registry.fee-token-not-in-assetlist";
+    pub const REGISTRY_OP_DENOM_NOT_ON_L1: &str = "registry.op-denom-not-on-l1";
+    pub const REGISTRY_MISSING_L1: &str = "registry.missing-l1";
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub rule: &'static str,
+    pub severity: Severity,
+    /// Registry file the diagnostic refers to, e.g. `chains/initia/chain.json`. Only set by
+    /// registry-wide checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// JSON path of the offending value, e.g. `$.assets[0].denom_units`.
+    pub path: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn error(rule: &'static str, path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            rule,
+            severity: Severity::Error,
+            file: None,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(
+        rule: &'static str,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            rule,
+            severity: Severity::Warning,
+            file: None,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}] ", self.severity, self.rule)?;
+        if let Some(file) = &self.file {
+            write!(f, "{file} ")?;
+        }
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+pub trait Validate {
+    fn validate(&self) -> Vec<Diagnostic>;
+}
+
+impl Validate for Asset {
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        validate_asset(self, "$", &mut out);
+        out
+    }
+}
+
+impl Validate for AssetList {
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        let mut seen = HashSet::new();
+        for (i, asset) in self.assets.iter().enumerate() {
+            let path = format!("$.assets[{i}]");
+            if !seen.insert(asset.base.as_str()) {
+                out.push(Diagnostic::error(
+                    rules::ASSETLIST_DUPLICATE_BASE,
+                    format!("{path}.base"),
+                    format!("base `{}` is listed more than once", asset.base),
+                ));
+            }
+            validate_asset(asset, &path, &mut out);
+        }
+        out
+    }
+}
+
+impl Validate for Chain {
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+
+        for (i, explorer) in self.explorers.iter().enumerate() {
+            if !explorer.tx_page.contains("${txHash}") {
+                out.push(Diagnostic::error(
+                    rules::CHAIN_EXPLORER_TX_PAGE,
+                    format!("$.explorers[{i}].tx_page"),
+                    "tx_page must contain the `${txHash}` placeholder",
+                ));
+            }
+            if !explorer.account_page.contains("${accountAddress}") {
+                out.push(Diagnostic::error(
+                    rules::CHAIN_EXPLORER_ACCOUNT_PAGE,
+                    format!("$.explorers[{i}].account_page"),
+                    "account_page must contain the `${accountAddress}` placeholder",
+                ));
+            }
+        }
+
+        for (i, token) in self.fees.fee_tokens.iter().enumerate() {
+            let path = format!("$.fees.fee_tokens[{i}]");
+            let tiers = [
+                ("fixed_min_gas_price", token.fixed_min_gas_price),
+                ("low_gas_price", token.low_gas_price),
+                ("average_gas_price", token.average_gas_price),
+                ("high_gas_price", token.high_gas_price),
+            ];
+            for (field, price) in tiers {
+                if let Some(price) = price.filter(|p| !p.is_finite() || *p < 0.0) {
+                    out.push(Diagnostic::error(
+                        rules::CHAIN_GAS_PRICE,
+                        format!("{path}.{field}"),
+                        format!("gas price {price} must be a non-negative number"),
+                    ));
+                }
+            }
+            let ordered = [
+                ("low_gas_price", token.low_gas_price),
+                ("average_gas_price", token.average_gas_price),
+                ("high_gas_price", token.high_gas_price),
+            ];
+            for pair in ordered.windows(2) {
+                if let [(lower_field, Some(lower)), (upper_field, Some(upper))] = pair
+                    && lower > upper
+                {
+                    out.push(Diagnostic::warning(
+                        rules::CHAIN_GAS_PRICE_ORDER,
+                        format!("{path}.{upper_field}"),
+                        format!("{upper_field} {upper} is lower than {lower_field} {lower}"),
+                    ));
+                }
+            }
+        }
+
+        if let Some(bridge_id) = &self.metadata.op_bridge_id
+            && bridge_id.parse::<u64>().is_err()
+        {
+            out.push(Diagnostic::error(
+                rules::CHAIN_OP_BRIDGE_ID,
+                "$.metadata.op_bridge_id",
+                format!("op_bridge_id `{bridge_id}` is not an unsigned integer"),
+            ));
+        }
+
+        if let Some(Ok(bridge_id)) = self.metadata.op_bridge_id.as_ref().map(|id| id.parse()) {
+            for (i, token) in self.fees.fee_tokens.iter().enumerate() {
+                if !matches!(token.parse_denom(), Ok(Denom::L2 { .. })) {
+                    continue;
+                }
+                let bridged = self
+                    .metadata
+                    .op_denoms
+                    .iter()
+                    .any(|d| Denom::l2_from_bridge(bridge_id, d).to_string() == token.denom);
+                if !bridged {
+                    out.push(Diagnostic::error(
+                        rules::CHAIN_OP_FEE_TOKEN,
+                        format!("$.fees.fee_tokens[{i}].denom"),
+                        format!(
+                            "`{}` is not the L2 denom of any op_denoms entry over bridge {bridge_id}",
+                            token.denom
+                        ),
+                    ));
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Runs every per-file rule with the file attached, followed by the cross-file checks of
+/// [`Registry::check_integrity`].
+impl Validate for Registry {
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        for chain in self.chains() {
+            let file = chain_file(&chain.chain_name);
+            out.extend(chain.validate().into_iter().map(|d| d.with_file(&file)));
+        }
+        for list in self.assetlists() {
+            let file = assetlist_file(&list.chain_name);
+            out.extend(list.validate().into_iter().map(|d| d.with_file(&file)));
+        }
+        for profile in self.profiles() {
+            let file = profile_file(&profile.name);
+            out.extend(profile.validate().into_iter().map(|d| d.with_file(&file)));
+        }
+        out.extend(self.check_integrity());
+        out
+    }
+}
+
+impl Validate for Profile {
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+        let is_hex_color = self
+            .color
+            .strip_prefix('#')
+            .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()));
+        if !is_hex_color {
+            out.push(Diagnostic::warning(
+                rules::PROFILE_COLOR,
+                "$.color",
+                format!("color `{}` is not a `#RRGGBB` hex color", self.color),
+            ));
+        }
+        out
+    }
+}
+
+pub(crate) fn chain_file(chain_name: &str) -> String {
+    format!("chains/{chain_name}/chain.json")
+}
+
+pub(crate) fn assetlist_file(chain_name: &str) -> String {
+    format!("chains/{chain_name}/assetlist.json")
+}
+
+pub(crate) fn profile_file(name: &str) -> String {
+    format!("profiles/{name}.json")
+}
+
+fn validate_asset(asset: &Asset, path: &str, out: &mut Vec<Diagnostic>) {
+    let units_path = format!("{path}.denom_units");
+
+    if !asset
+        .denom_units
+        .iter()
+        .any(|u| u.exponent == 0 && u.denom == asset.base)
+    {
+        out.push(Diagnostic::error(
+            rules::ASSET_BASE_UNIT,
+            units_path.clone(),
+            format!(
+                "denom_units has no exponent-0 unit matching base `{}`",
+                asset.base
+            ),
+        ));
+    }
+
+    if !asset.denom_units.iter().any(|u| u.denom == asset.display) {
+        out.push(Diagnostic::error(
+            rules::ASSET_DISPLAY_UNIT,
+            format!("{path}.display"),
+            format!("display `{}` does not name a denom unit", asset.display),
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    for (i, unit) in asset.denom_units.iter().enumerate() {
+        if !seen.insert(unit.denom.as_str()) {
+            out.push(Diagnostic::error(
+                rules::ASSET_DUPLICATE_UNIT,
+                format!("{units_path}[{i}].denom"),
+                format!("denom unit `{}` is listed more than once", unit.denom),
+            ));
+        }
+    }
+
+    for (i, pair) in asset.denom_units.windows(2).enumerate() {
+        if pair[1].exponent <= pair[0].exponent {
+            out.push(Diagnostic::warning(
+                rules::ASSET_UNIT_ORDER,
+                format!("{units_path}[{}].exponent", i + 1),
+                "denom_units should be sorted by strictly increasing exponent",
+            ));
+        }
+    }
+
+    for (i, trace) in asset.traces.iter().enumerate() {
+        let trace_path = format!("{path}.traces[{i}]");
+        let actual = denom_after_trace(asset, i);
+        match trace {
+            Trace::Op {
+                counterparty,
+                chain,
+            } => {
+                let Ok(bridge_id) = chain.bridge_id.parse() else {
+                    out.push(Diagnostic::error(
+                        rules::ASSET_OP_DENOM,
+                        format!("{trace_path}.chain.bridge_id"),
+                        format!("bridge_id `{}` is not an unsigned integer", chain.bridge_id),
+                    ));
+                    continue;
+                };
+                let expected =
+                    Denom::l2_from_bridge(bridge_id, &counterparty.base_denom).to_string();
+                if actual != expected {
+                    out.push(Diagnostic::error(
+                        rules::ASSET_OP_DENOM,
+                        denom_after_trace_path(asset, path, i),
+                        format!(
+                            "`{actual}` does not match `{expected}` derived from bridge {bridge_id} and `{}`",
+                            counterparty.base_denom
+                        ),
+                    ));
+                }
+            }
+            Trace::Ibc {
+                counterparty,
+                chain,
+            } => {
+                if !ibc_path_ok(&chain.path, &chain.channel_id, &counterparty.base_denom) {
+                    out.push(Diagnostic::error(
+                        rules::ASSET_IBC_PATH,
+                        format!("{trace_path}.chain.path"),
+                        format!(
+                            "path `{}` must start with `<port>/{}/` and lead back to `{}`",
+                            chain.path, chain.channel_id, counterparty.base_denom
+                        ),
+                    ));
+                }
+
+                let expected = chain.ibc_denom().to_string();
+                if actual != expected {
+                    out.push(Diagnostic::error(
+                        rules::ASSET_IBC_HASH,
+                        denom_after_trace_path(asset, path, i),
+                        format!(
+                            "`{actual}` does not match `{expected}` derived from path `{}`",
+                            chain.path
+                        ),
+                    ));
+                }
+            }
+            Trace::Wrapped { .. } => {}
+        }
+    }
+}
+
+/// Checks that `path` enters through `channel_id` and that what follows it is the trace of
+/// `base_denom`: the denom itself, or for a multi-hop voucher whose counterparty denom is an
+/// `ibc/<HASH>`, a path that hashes to it and ends in a non-`ibc/` denom.
+fn ibc_path_ok(path: &str, channel_id: &str, base_denom: &str) -> bool {
+    let mut parts = path.splitn(3, '/');
+    let (Some(port), Some(channel), Some(tail)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    if port.is_empty() || channel != channel_id || tail.is_empty() || tail.starts_with("ibc/") {
+        return false;
+    }
+    match base_denom.parse::<Denom>() {
+        Ok(ibc @ Denom::Ibc { .. }) => Denom::ibc_from_path(tail) == ibc,
+        _ => tail == base_denom || tail.ends_with(&format!("/{base_denom}")),
+    }
+}
+
+/// Returns the denom an asset carries right after the `i`-th trace hop: the next hop's
+/// counterparty denom, or the asset's own base for the last hop.
+fn denom_after_trace(asset: &Asset, i: usize) -> &str {
+    asset
+        .traces
+        .get(i + 1)
+        .map(|next| next.base_denom())
+        .unwrap_or(&asset.base)
+}
+
+fn denom_after_trace_path(asset: &Asset, path: &str, i: usize) -> String {
+    if i + 1 < asset.traces.len() {
+        format!("{path}.traces[{}].counterparty.base_denom", i + 1)
+    } else {
+        format!("{path}.base")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asset::DenomUnit;
+    use crate::common::ImageType;
+
+    fn asset(base: &str, display: &str, units: &[(&str, u32)]) -> Asset {
+        Asset {
+            description: "Test token".to_string(),
+            denom_units: units
+                .iter()
+                .map(|(denom, exponent)| DenomUnit {
+                    denom: denom.to_string(),
+                    exponent: *exponent,
+                })
+                .collect(),
+            base: base.to_string(),
+            display: display.to_string(),
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            coingecko_id: None,
+            type_asset: None,
+            images: vec![],
+            logo_uris: ImageType::PNG("https://test.com/logo.png".to_string()),
+            traces: vec![],
+        }
+    }
+
+    fn rules_of(diagnostics: &[Diagnostic]) -> Vec<&'static str> {
+        diagnostics.iter().map(|d| d.rule).collect()
+    }
+
+    #[test]
+    fn test_valid_asset() {
+        let asset = asset("utest", "TEST", &[("utest", 0), ("TEST", 6)]);
+        assert!(asset.validate().is_empty());
+    }
+
+    #[test]
+    fn test_asset_rules() {
+        let missing_base = asset("utest", "TEST", &[("utest", 6), ("TEST", 6)]);
+        let diagnostics = missing_base.validate();
+        assert_eq!(
+            rules_of(&diagnostics),
+            vec![rules::ASSET_BASE_UNIT, rules::ASSET_UNIT_ORDER]
+        );
+        assert_eq!(diagnostics[0].path, "$.denom_units");
+        assert!(diagnostics[0].is_error());
+        assert!(!diagnostics[1].is_error());
+
+        let bad_display = asset("utest", "TST", &[("utest", 0), ("utest", 6)]);
+        assert_eq!(
+            rules_of(&bad_display.validate()),
+            vec![rules::ASSET_DISPLAY_UNIT, rules::ASSET_DUPLICATE_UNIT]
+        );
+    }
+
+    #[test]
+    fn test_ibc_trace_rules() {
+        let json = r#"{
+            "description": "OP-IBC bridged INIT of Yominet",
+            "denom_units": [
+                {"denom": "ibc/07FFEEEE36370551554531FF7189EB2D3353B187CB6CE715DA1291C3A4B4EAC2", "exponent": 0},
+                {"denom": "INIT.yominet", "exponent": 6}
+            ],
+            "base": "ibc/07FFEEEE36370551554531FF7189EB2D3353B187CB6CE715DA1291C3A4B4EAC2",
+            "display": "INIT.yominet",
+            "name": "INIT.yominet",
+            "symbol": "INIT.yominet",
+            "traces": [
+                {
+                    "type": "op",
+                    "counterparty": {"base_denom": "uinit", "chain_name": "initia"},
+                    "chain": {"bridge_id": "11"}
+                },
+                {
+                    "type": "ibc",
+                    "counterparty": {
+                        "chain_name": "yominet",
+                        "base_denom": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06",
+                        "channel_id": "channel-0"
+                    },
+                    "chain": {
+                        "channel_id": "channel-25",
+                        "path": "transfer/channel-25/l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06"
+                    }
+                }
+            ],
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/yominet.ibcopinit.png"}
+        }"#;
+        let mut asset: Asset = serde_json::from_str(json).expect("Failed to deserialize asset");
+        assert!(asset.validate().is_empty());
+
+        let good_base = asset.base.clone();
+        asset.base = good_base.replace("07FF", "07FE");
+        asset.denom_units[0].denom = asset.base.clone();
+        let diagnostics = asset.validate();
+        assert_eq!(rules_of(&diagnostics), vec![rules::ASSET_IBC_HASH]);
+        assert_eq!(diagnostics[0].path, "$.base");
+        assert!(diagnostics[0].message.contains(&good_base));
+
+        asset.base = good_base.clone();
+        asset.denom_units[0].denom = good_base;
+        if let Trace::Ibc { chain, .. } = &mut asset.traces[1] {
+            chain.path = chain.path.replace("channel-25", "channel-26");
+        }
+        assert_eq!(
+            rules_of(&asset.validate()),
+            vec![rules::ASSET_IBC_PATH, rules::ASSET_IBC_HASH]
+        );
+    }
+
+    #[test]
+    fn test_multi_hop_ibc_trace_rules() {
+        let json = r#"{
+            "description": "Noble USDC routed through Osmosis",
+            "denom_units": [
+                {"denom": "ibc/3C58AC56E392E53BBA24A1021D9D7615CD229226111F089AE6A95F05E50C776F", "exponent": 0},
+                {"denom": "USDC.osmo", "exponent": 6}
+            ],
+            "base": "ibc/3C58AC56E392E53BBA24A1021D9D7615CD229226111F089AE6A95F05E50C776F",
+            "display": "USDC.osmo",
+            "name": "USDC.osmo",
+            "symbol": "USDC.osmo",
+            "traces": [
+                {
+                    "type": "ibc",
+                    "counterparty": {"chain_name": "noble", "base_denom": "uusdc", "channel_id": "channel-1"},
+                    "chain": {"channel_id": "channel-750", "path": "transfer/channel-750/uusdc"}
+                },
+                {
+                    "type": "ibc",
+                    "counterparty": {
+                        "chain_name": "osmosis",
+                        "base_denom": "ibc/498A0751C798A0D9A389AA3691123DADA57DAA4FE165D5C75894505B876BA6E4",
+                        "channel_id": "channel-87"
+                    },
+                    "chain": {
+                        "channel_id": "channel-4",
+                        "path": "transfer/channel-4/transfer/channel-750/uusdc"
+                    }
+                }
+            ],
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/USDC.png"}
+        }"#;
+        let mut asset: Asset = serde_json::from_str(json).expect("Failed to deserialize asset");
+        assert!(asset.validate().is_empty());
+
+        let good_path = "transfer/channel-4/transfer/channel-750/uusdc".to_string();
+        for path in [
+            "transfer/channel-4/transfer/channel-751/uusdc",
+            "transfer/channel-4/ibc/498A0751C798A0D9A389AA3691123DADA57DAA4FE165D5C75894505B876BA6E4",
+        ] {
+            if let Trace::Ibc { chain, .. } = &mut asset.traces[1] {
+                chain.path = path.to_string();
+            }
+            assert_eq!(
+                rules_of(&asset.validate()),
+                vec![rules::ASSET_IBC_PATH, rules::ASSET_IBC_HASH]
+            );
+        }
+
+        if let Trace::Ibc { chain, .. } = &mut asset.traces[1] {
+            chain.path = good_path;
+            chain.channel_id = "channel-5".to_string();
+        }
+        assert_eq!(rules_of(&asset.validate()), vec![rules::ASSET_IBC_PATH]);
+    }
+
+    #[test]
+    fn test_op_trace_rules() {
+        let json = r#"{
+            "description": "INIT on Rena",
+            "denom_units": [
+                {"denom": "l2/9d3d65bf3329e45ad659f9cbee7d6dc7b6246b001e32131a9b465215eab90562", "exponent": 0},
+                {"denom": "INIT", "exponent": 6}
+            ],
+            "base": "l2/9d3d65bf3329e45ad659f9cbee7d6dc7b6246b001e32131a9b465215eab90562",
+            "display": "INIT",
+            "name": "Initia Native Token",
+            "symbol": "INIT",
+            "traces": [
+                {
+                    "type": "op",
+                    "counterparty": {"base_denom": "uinit", "chain_name": "initia"},
+                    "chain": {"bridge_id": "30"}
+                }
+            ],
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/INIT.png"}
+        }"#;
+        let mut asset: Asset = serde_json::from_str(json).expect("Failed to deserialize asset");
+        assert!(asset.validate().is_empty());
+
+        if let Trace::Op { chain, .. } = &mut asset.traces[0] {
+            chain.bridge_id = "31".to_string();
+        }
+        let diagnostics = asset.validate();
+        assert_eq!(rules_of(&diagnostics), vec![rules::ASSET_OP_DENOM]);
+        assert_eq!(diagnostics[0].path, "$.base");
+
+        if let Trace::Op { chain, .. } = &mut asset.traces[0] {
+            chain.bridge_id = "thirty".to_string();
+        }
+        let diagnostics = asset.validate();
+        assert_eq!(rules_of(&diagnostics), vec![rules::ASSET_OP_DENOM]);
+        assert_eq!(diagnostics[0].path, "$.traces[0].chain.bridge_id");
+    }
+
+    #[test]
+    fn test_op_fee_token_rule() {
+        let json = r#"{
+            "chain_id": "rena-nuwa-1",
+            "chain_name": "rena",
+            "pretty_name": "Rena",
+            "fees": {
+                "fee_tokens": [
+                    {"denom": "l2/9d3d65bf3329e45ad659f9cbee7d6dc7b6246b001e32131a9b465215eab90562"},
+                    {"denom": "l2/8f73cfaf153520f511b4fc0bd71d60d64b4e19eff04a350e642718a3c1ab3b06"},
+                    {"denom": "urena"}
+                ]
+            },
+            "apis": {},
+            "explorers": [],
+            "metadata": {"op_bridge_id": "30", "op_denoms": ["uinit"]},
+            "logo_URIs": {"png": "https://registry.initia.xyz/images/rena.png"},
+            "slip44": 60,
+            "bech32_prefix": "init",
+            "network_type": "mainnet"
+        }"#;
+        let chain: Chain = serde_json::from_str(json).expect("Failed to deserialize chain");
+
+        let diagnostics = chain.validate();
+        assert_eq!(rules_of(&diagnostics), vec![rules::CHAIN_OP_FEE_TOKEN]);
+        assert_eq!(diagnostics[0].path, "$.fees.fee_tokens[1].denom");
+    }
+
+    #[test]
+    fn test_assetlist_paths() {
+        let list = AssetList {
+            schema: "../../assetlist.schema.json".to_string(),
+            chain_name: "test".to_string(),
+            assets: vec![
+                asset("utest", "TEST", &[("utest", 0), ("TEST", 6)]),
+                asset("utest", "TEST", &[("TEST", 6)]),
+            ],
+        };
+
+        let diagnostics = list.validate();
+        assert_eq!(
+            rules_of(&diagnostics),
+            vec![rules::ASSETLIST_DUPLICATE_BASE, rules::ASSET_BASE_UNIT]
+        );
+        assert_eq!(diagnostics[0].path, "$.assets[1].base");
+        assert_eq!(diagnostics[1].path, "$.assets[1].denom_units");
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "error[asset.base-unit] $.assets[1].denom_units: denom_units has no exponent-0 unit matching base `utest`"
+        );
+    }
+
+    #[test]
+    fn test_chain_rules() {
+        let json = r#"{
+            "chain_id": "test-1",
+            "chain_name": "test",
+            "pretty_name": "Test",
+            "fees": {
+                "fee_tokens": [
+                    {"denom": "utest", "fixed_min_gas_price": -1, "low_gas_price": 0.2, "average_gas_price": 0.1, "high_gas_price": 0.3}
+                ]
+            },
+            "apis": {},
+            "explorers": [
+                {
+                    "kind": "test scan",
+                    "url": "https://scan.test.com",
+                    "tx_page": "https://scan.test.com/txs/{hash}",
+                    "account_page": "https://scan.test.com/accounts/${accountAddress}"
+                }
+            ],
+            "metadata": {"op_bridge_id": "eleven"},
+            "logo_URIs": {"png": "https://test.com/logo.png"},
+            "slip44": 118,
+            "bech32_prefix": "test",
+            "network_type": "testnet"
+        }"#;
+        let chain: Chain = serde_json::from_str(json).expect("Failed to deserialize chain");
+
+        let diagnostics = chain.validate();
+        assert_eq!(
+            rules_of(&diagnostics),
+            vec![
+                rules::CHAIN_EXPLORER_TX_PAGE,
+                rules::CHAIN_GAS_PRICE,
+                rules::CHAIN_GAS_PRICE_ORDER,
+                rules::CHAIN_OP_BRIDGE_ID,
+            ]
+        );
+        assert_eq!(diagnostics[0].path, "$.explorers[0].tx_page");
+        assert_eq!(
+            diagnostics[1].path,
+            "$.fees.fee_tokens[0].fixed_min_gas_price"
+        );
+        assert_eq!(
+            diagnostics[2].path,
+            "$.fees.fee_tokens[0].average_gas_price"
+        );
+    }
+
+    #[test]
+    fn test_profile_color() {
+        let json = r#"{
+            "name": "test",
+            "pretty_name": "Test",
+            "category": "Gaming",
+            "description": "Test profile",
+            "logo": "https://test.com/logo.png",
+            "color": "blue",
+            "status": "live",
+            "social": {"website": "https://test.com", "twitter": "https://x.com/test"}
+        }"#;
+        let profile: Profile = serde_json::from_str(json).expect("Failed to deserialize profile");
+
+        let diagnostics = profile.validate();
+        assert_eq!(rules_of(&diagnostics), vec![rules::PROFILE_COLOR]);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+    }
+}